@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use crate::api::{SubsonicClient, API_VERSION};
+
+/// What the server told us about itself during the startup handshake
+/// (`ping` + `getOpenSubsonicExtensions`).
+///
+/// Optional features check this instead of assuming support, so they simply
+/// stay off on plain Subsonic servers.
+#[derive(Debug, Clone, Default)]
+pub struct ServerCapabilities {
+    pub api_version:    Option<String>,
+    pub server_type:    Option<String>,
+    pub server_version: Option<String>,
+    pub open_subsonic:  bool,
+    /// Extension name → supported extension versions.
+    pub extensions:     HashMap<String, Vec<i32>>,
+}
+
+impl ServerCapabilities {
+    /// Runs the handshake. Never fails – an unreachable server or a server
+    /// without OpenSubsonic support just yields fewer capabilities.
+    pub async fn detect(client: &SubsonicClient) -> Self {
+        let mut caps = ServerCapabilities::default();
+        let Ok(ping) = client.ping().await else { return caps; };
+        caps.api_version    = ping.version;
+        caps.server_type    = ping.server_type;
+        caps.server_version = ping.server_version;
+        caps.open_subsonic  = ping.open_subsonic;
+
+        if caps.open_subsonic {
+            if let Ok(extensions) = client.get_open_subsonic_extensions().await {
+                caps.extensions = extensions.into_iter().map(|e| (e.name, e.versions)).collect();
+            }
+        }
+        caps
+    }
+
+    pub fn supports(&self, extension: &str) -> bool {
+        self.extensions.contains_key(extension)
+    }
+
+    /// `getLyricsBySongId` with synced (timestamped) lines.
+    pub fn synced_lyrics(&self) -> bool { self.supports("songLyrics") }
+
+    /// Several `musicFolderId` parameters per request. Navidrome reads them
+    /// all; other servers only honour one.
+    pub fn multiple_music_folders(&self) -> bool {
+        self.server_type.as_deref().is_some_and(|t| t.eq_ignore_ascii_case("navidrome"))
+    }
+
+    /// Parameters may be sent as a POST form body instead of the query string.
+    pub fn form_post(&self) -> bool { self.supports("formPost") }
+
+    /// REST API version to send as `v`: ours, or the server's if it is older.
+    pub fn negotiated_api_version(&self) -> String {
+        match self.api_version.as_deref() {
+            Some(server) if version_parts(server) < version_parts(API_VERSION) => server.to_string(),
+            _ => API_VERSION.to_string(),
+        }
+    }
+
+    /// One-line description for the status bar, e.g. `navidrome 0.53.3 (API 1.16.1, OpenSubsonic)`.
+    pub fn summary(&self) -> String {
+        let mut s = match (&self.server_type, &self.server_version) {
+            (Some(t), Some(v)) => format!("{} {}", t, v),
+            (Some(t), None)    => t.clone(),
+            _                  => "Subsonic server".to_string(),
+        };
+        if let Some(v) = &self.api_version {
+            s.push_str(&format!(" (API {}{})", v, if self.open_subsonic { ", OpenSubsonic" } else { "" }));
+        }
+        s
+    }
+}
+
+/// `1.16.1` → `(1, 16, 1)`; missing or garbled parts count as 0.
+fn version_parts(version: &str) -> (u32, u32, u32) {
+    let mut parts = version.split('.').map(|p| p.parse::<u32>().unwrap_or(0));
+    (parts.next().unwrap_or(0), parts.next().unwrap_or(0), parts.next().unwrap_or(0))
+}
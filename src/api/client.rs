@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client, Response, StatusCode,
+};
+
+use crate::api::{build_auth_query, models::SubsonicResponse, SubsonicError, API_VERSION};
+use crate::config::Config;
+
+/// Which failures [`SubsonicClient::send`] retries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Retry {
+    /// Connect errors only – the server never saw the request, so repeating
+    /// it is safe even for calls with side effects (createShare, scrobble, …).
+    Connect,
+    /// Also timeouts, 5xx and 429. Only for read-only calls, which may run
+    /// twice without harm.
+    Transient,
+}
+
+/// Shared Subsonic API client.
+///
+/// Owns the config and a single pooled `reqwest::Client`, so every panel
+/// navigation reuses the same keep-alive connection instead of paying a fresh
+/// TLS handshake. Cheap to clone — the HTTP pool is reference-counted.
+#[derive(Clone)]
+pub struct SubsonicClient {
+    config:        Config,
+    http:          Client,
+    form_post:     bool,
+    music_folders: Vec<String>,
+    api_version:   String,
+}
+
+impl SubsonicClient {
+    pub fn new(config: Config) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &config.server.auth.headers {
+            let mut value = HeaderValue::from_str(value)
+                .map_err(|_| anyhow::anyhow!("Invalid value for header '{}'", name))?;
+            value.set_sensitive(true);
+            headers.insert(HeaderName::from_bytes(name.as_bytes())?, value);
+        }
+        let http = Client::builder()
+            .default_headers(headers)
+            .timeout(Duration::from_secs(config.server.timeout_secs))
+            .connect_timeout(Duration::from_secs(config.server.timeout_secs.min(10)))
+            .pool_idle_timeout(Duration::from_secs(90))
+            .tcp_keepalive(Duration::from_secs(30))
+            .user_agent(concat!("TerminalDrome/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+        Ok(Self { config, http, form_post: false, music_folders: Vec::new(), api_version: API_VERSION.to_string() })
+    }
+
+    pub fn config(&self) -> &Config { &self.config }
+
+    /// Send parameters as a POST form body (OpenSubsonic `formPost`), which
+    /// keeps credentials out of server access logs and lifts URL length limits.
+    pub fn set_form_post(&mut self, enabled: bool) { self.form_post = enabled; }
+
+    /// REST API version sent as `v` – our own until the handshake found an older server.
+    pub fn set_api_version(&mut self, version: String) { self.api_version = version; }
+
+    pub fn api_version(&self) -> &str { &self.api_version }
+
+    /// Libraries that artist lists, search, album lists and random songs are
+    /// limited to; empty means all of them.
+    pub fn set_music_folders(&mut self, ids: Vec<String>) { self.music_folders = ids; }
+
+    pub fn music_folders(&self) -> &[String] { &self.music_folders }
+
+    /// Appends one `musicFolderId` per selected library.
+    pub(crate) fn push_music_folders<'a>(&'a self, params: &mut Vec<(&'a str, &'a str)>) {
+        params.extend(self.music_folders.iter().map(|id| ("musicFolderId", id.as_str())));
+    }
+
+    fn endpoint_url(&self, endpoint: &str) -> String {
+        format!("{}/rest/{}", self.config.server.url.trim_end_matches('/'), endpoint)
+    }
+
+    /// Sends `/rest/<endpoint>` with auth + `params`, retrying the failures
+    /// `retry` allows with exponential backoff.
+    pub async fn send(&self, endpoint: &str, params: &[(&str, &str)], retry: Retry) -> Result<Response> {
+        let url     = self.endpoint_url(endpoint);
+        let retries = self.config.server.retries;
+        let mut attempt = 0u32;
+        loop {
+            let mut query = build_auth_query(&self.config, &self.api_version);
+            query.extend(params.iter().map(|(k, v)| (k.to_string(), v.to_string())));
+
+            let request = if self.form_post {
+                self.http.post(&url).form(&query)
+            } else {
+                self.http.get(&url).query(&query)
+            };
+            let result = request.send().await;
+            let retryable = match (&result, retry) {
+                (Err(e), _) if e.is_connect() => true,
+                (Err(e), Retry::Transient)    => e.is_timeout(),
+                (Ok(resp), Retry::Transient)  => {
+                    resp.status().is_server_error() || resp.status() == StatusCode::TOO_MANY_REQUESTS
+                }
+                _ => false,
+            };
+            if !retryable || attempt >= retries {
+                let response = result?;
+                if !response.status().is_success() {
+                    anyhow::bail!("{} failed: HTTP {}", endpoint, response.status());
+                }
+                return Ok(response);
+            }
+            tokio::time::sleep(Duration::from_millis(250 * 2u64.pow(attempt))).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends a request and parses the `subsonic-response` envelope. A
+    /// `status="failed"` response becomes a [`SubsonicError`]. Only connect
+    /// errors are retried; read-only calls use [`Self::fetch`].
+    pub async fn get(&self, endpoint: &str, params: &[(&str, &str)]) -> Result<SubsonicResponse> {
+        self.request(endpoint, params, Retry::Connect).await
+    }
+
+    /// `get` for read-only calls, which also retries timeouts and server errors.
+    pub async fn fetch(&self, endpoint: &str, params: &[(&str, &str)]) -> Result<SubsonicResponse> {
+        self.request(endpoint, params, Retry::Transient).await
+    }
+
+    async fn request(&self, endpoint: &str, params: &[(&str, &str)], retry: Retry) -> Result<SubsonicResponse> {
+        let body = self.send(endpoint, params, retry).await?.text().await?;
+        let mut parsed: SubsonicResponse = serde_json::from_str(&body)?;
+        if parsed.response.status != "ok" {
+            let err = parsed.response.error.take()
+                .map(SubsonicError::from)
+                .unwrap_or_else(|| SubsonicError::Generic(format!("{} failed", endpoint)));
+            return Err(err.into());
+        }
+        Ok(parsed)
+    }
+}
@@ -1,175 +1,537 @@
 use anyhow::Result;
-use crate::config::Config;
-use crate::api::{build_auth_query, models::*};
-
-pub async fn get_artists(config: &Config) -> Result<Vec<Artist>> {
-    let client   = reqwest::Client::new();
-    let params   = build_auth_query(config);
-    let response = client
-        .get(format!("{}/rest/getArtists", config.server.url))
-        .query(&params).send().await?;
-    let body: SubsonicResponse = serde_json::from_str(&response.text().await?)?;
-    match body.response.content {
-        ContentType::Artists { artists } =>
-            Ok(artists.index.into_iter().flat_map(|g| g.artist).collect()),
-        _ => anyhow::bail!("Unexpected response for artists"),
+use crate::api::{client::{Retry, SubsonicClient}, models::*, SubsonicError};
+
+impl SubsonicClient {
+    /// Checks connectivity and credentials; the envelope carries the server's
+    /// API version, type and OpenSubsonic flag.
+    pub async fn ping(&self) -> Result<SubsonicContent> {
+        Ok(self.fetch("ping", &[]).await?.response)
     }
-}
 
-pub async fn get_artist_albums(artist_id: &str, config: &Config) -> Result<Vec<Album>> {
-    let client     = reqwest::Client::new();
-    let mut params = build_auth_query(config);
-    params.push(("id".to_string(), artist_id.to_string()));
-    let response = client
-        .get(format!("{}/rest/getArtist", config.server.url))
-        .query(&params).send().await?;
-    let body: SubsonicResponse = serde_json::from_str(&response.text().await?)?;
-    match body.response.content {
-        ContentType::Albums { artist } => Ok(artist.album),
-        _ => anyhow::bail!("Unexpected response for albums"),
+    pub async fn get_open_subsonic_extensions(&self) -> Result<Vec<OpenSubsonicExtension>> {
+        let body = self.fetch("getOpenSubsonicExtensions", &[]).await?;
+        match body.response.content {
+            ContentType::OpenSubsonicExtensions { extensions } => Ok(extensions),
+            _ => Ok(Vec::new()),
+        }
     }
-}
 
-pub async fn get_album_songs(album_id: &str, config: &Config) -> Result<Vec<Song>> {
-    let client     = reqwest::Client::new();
-    let mut params = build_auth_query(config);
-    params.push(("id".to_string(), album_id.to_string()));
-    let response = client
-        .get(format!("{}/rest/getAlbum", config.server.url))
-        .query(&params).send().await?;
-    let body: SubsonicResponse = serde_json::from_str(&response.text().await?)?;
-    match body.response.content {
-        ContentType::Songs { album } => Ok(album.song),
-        _ => anyhow::bail!("Unexpected response for songs"),
+    pub async fn get_music_folders(&self) -> Result<Vec<MusicFolder>> {
+        let body = self.fetch("getMusicFolders", &[]).await?;
+        match body.response.content {
+            ContentType::MusicFolders { music_folders } => Ok(music_folders.music_folder),
+            _ => anyhow::bail!("Unexpected response for music folders"),
+        }
     }
-}
 
-pub async fn get_playlists(config: &Config) -> Result<Vec<Playlist>> {
-    let client   = reqwest::Client::new();
-    let params   = build_auth_query(config);
-    let response = client
-        .get(format!("{}/rest/getPlaylists", config.server.url))
-        .query(&params).send().await?;
-    let body: SubsonicResponse = serde_json::from_str(&response.text().await?)?;
-    match body.response.content {
-        ContentType::Playlists { playlists } => Ok(playlists.playlist),
-        _ => anyhow::bail!("Unexpected response for playlists"),
+    /// Root of the file tree: top-level folders as `is_dir` entries, then loose tracks.
+    pub async fn get_indexes(&self) -> Result<Vec<Song>> {
+        let mut params = Vec::new();
+        self.push_music_folders(&mut params);
+        let body = self.fetch("getIndexes", &params).await?;
+        match body.response.content {
+            ContentType::Indexes { indexes } => Ok(indexes.index
+                .into_iter()
+                .flat_map(|group| group.artist)
+                .map(|folder| Song { id: folder.id, title: folder.name, is_dir: true, ..Song::default() })
+                .chain(indexes.child)
+                .collect()),
+            _ => anyhow::bail!("Unexpected response for indexes"),
+        }
+    }
+
+    pub async fn get_music_directory(&self, id: &str) -> Result<MusicDirectory> {
+        let body = self.fetch("getMusicDirectory", &[("id", id)]).await?;
+        match body.response.content {
+            ContentType::Directory { directory } => Ok(directory),
+            _ => anyhow::bail!("Unexpected response for music directory"),
+        }
     }
-}
 
-pub async fn get_playlist_songs(playlist_id: &str, config: &Config) -> Result<Vec<Song>> {
-    let client     = reqwest::Client::new();
-    let mut params = build_auth_query(config);
-    params.push(("id".to_string(), playlist_id.to_string()));
-    let response = client
-        .get(format!("{}/rest/getPlaylist", config.server.url))
-        .query(&params).send().await?;
-    let body: SubsonicResponse = serde_json::from_str(&response.text().await?)?;
-    match body.response.content {
-        ContentType::PlaylistDetail { playlist } => Ok(playlist.entry),
-        _ => anyhow::bail!("Unexpected response for playlist songs"),
+    pub async fn get_artists(&self) -> Result<Vec<Artist>> {
+        let mut params = Vec::new();
+        self.push_music_folders(&mut params);
+        let body = self.fetch("getArtists", &params).await?;
+        match body.response.content {
+            ContentType::Artists { artists } =>
+                Ok(artists.index.into_iter().flat_map(|g| g.artist).collect()),
+            _ => anyhow::bail!("Unexpected response for artists"),
+        }
     }
-}
 
-pub async fn get_random_songs(config: &Config, count: u16) -> Result<Vec<Song>> {
-    let client     = reqwest::Client::new();
-    let mut params = build_auth_query(config);
-    params.push(("size".to_string(), count.to_string()));
-    let response = client
-        .get(format!("{}/rest/getRandomSongs", config.server.url))
-        .query(&params).send().await?;
-    let body: SubsonicResponse = match serde_json::from_str(&response.text().await?) {
-        Ok(p)  => p,
-        Err(e) => {
-            eprintln!("getRandomSongs parse error: {}", e);
-            anyhow::bail!("Failed to parse getRandomSongs");
-        }
-    };
-    match body.response.content {
-        ContentType::RandomSongs { random_songs } => Ok(random_songs.song),
-        other => {
-            eprintln!("Unexpected getRandomSongs response: {:#?}", other);
-            Ok(Vec::new())
+    pub async fn get_artist_albums(&self, artist_id: &str) -> Result<Vec<Album>> {
+        let body = self.fetch("getArtist", &[("id", artist_id)]).await?;
+        match body.response.content {
+            ContentType::Albums { artist } => Ok(artist.album),
+            _ => anyhow::bail!("Unexpected response for albums"),
         }
     }
-}
 
-pub async fn search_songs(query: &str, config: &Config) -> Result<Vec<Song>> {
-    let client = reqwest::Client::new();
-    let mut params = build_auth_query(config);
-    params.push(("query".to_string(), query.to_string()));
-    params.push(("songCount".to_string(), "100".to_string()));
-    let response = client
-        .get(format!("{}/rest/search3", config.server.url))
-        .query(&params)
-        .send()
-        .await?;
-
-    let body = response.text().await?;
-
-    // Versuche, das JSON zu parsen
-    let json: serde_json::Value = match serde_json::from_str(&body) {
-        Ok(v) => v,
-        Err(e) => {
-            eprintln!("JSON Parse Error: {}", e);
-            return Ok(Vec::new());
-        }
-    };
-
-    // Extrahiere die Songs aus dem Pfad subsonic-response.searchResult3.song
-    if let Some(songs) = json
-        .get("subsonic-response")
-        .and_then(|r| r.get("searchResult3"))
-        .and_then(|sr| sr.get("song"))
-        .and_then(|s| s.as_array())
-    {
-        let mut result = Vec::new();
-        for item in songs {
-            if let Ok(song) = serde_json::from_value::<Song>(item.clone()) {
-                result.push(song);
-            } else {
-                eprintln!("Failed to parse a song item: {:?}", item);
+    pub async fn get_album_songs(&self, album_id: &str) -> Result<Vec<Song>> {
+        let body = self.fetch("getAlbum", &[("id", album_id)]).await?;
+        match body.response.content {
+            ContentType::Songs { album } => Ok(album.song),
+            _ => anyhow::bail!("Unexpected response for songs"),
+        }
+    }
+
+    pub async fn get_playlists(&self) -> Result<Vec<Playlist>> {
+        let body = self.fetch("getPlaylists", &[]).await?;
+        match body.response.content {
+            ContentType::Playlists { playlists } => Ok(playlists.playlist),
+            _ => anyhow::bail!("Unexpected response for playlists"),
+        }
+    }
+
+    pub async fn get_playlist_songs(&self, playlist_id: &str) -> Result<Vec<Song>> {
+        let body = self.fetch("getPlaylist", &[("id", playlist_id)]).await?;
+        match body.response.content {
+            ContentType::PlaylistDetail { playlist } => Ok(playlist.entry),
+            _ => anyhow::bail!("Unexpected response for playlist songs"),
+        }
+    }
+
+    /// Creates a playlist named `name` with `song_ids`.
+    pub async fn create_playlist(&self, name: &str, song_ids: &[String]) -> Result<()> {
+        let mut params = vec![("name", name)];
+        params.extend(song_ids.iter().map(|id| ("songId", id.as_str())));
+        self.get("createPlaylist", &params).await?;
+        Ok(())
+    }
+
+    /// Overwrites the songs of an existing playlist – the only way the API
+    /// offers to reorder one.
+    pub async fn replace_playlist_songs(&self, playlist_id: &str, song_ids: &[String]) -> Result<()> {
+        let mut params = vec![("playlistId", playlist_id)];
+        params.extend(song_ids.iter().map(|id| ("songId", id.as_str())));
+        self.get("createPlaylist", &params).await?;
+        Ok(())
+    }
+
+    pub async fn update_playlist(&self, playlist_id: &str, update: &PlaylistUpdate) -> Result<()> {
+        let public  = update.public.map(|p| p.to_string());
+        let indexes: Vec<String> = update.song_indexes_to_remove.iter().map(|i| i.to_string()).collect();
+        let mut params = vec![("playlistId", playlist_id)];
+        if let Some(name)    = &update.name    { params.push(("name", name)); }
+        if let Some(comment) = &update.comment { params.push(("comment", comment)); }
+        if let Some(public)  = &public         { params.push(("public", public)); }
+        params.extend(update.song_ids_to_add.iter().map(|id| ("songIdToAdd", id.as_str())));
+        params.extend(indexes.iter().map(|i| ("songIndexToRemove", i.as_str())));
+        self.get("updatePlaylist", &params).await?;
+        Ok(())
+    }
+
+    pub async fn delete_playlist(&self, playlist_id: &str) -> Result<()> {
+        self.get("deletePlaylist", &[("id", playlist_id)]).await?;
+        Ok(())
+    }
+
+    /// One page of `getAlbumList2` (`size` ≤ 500).
+    pub async fn get_album_list(&self, list: &AlbumListType, size: u32, offset: u32) -> Result<Vec<Album>> {
+        let size   = size.to_string();
+        let offset = offset.to_string();
+        let mut params = vec![("type", list.api_name()), ("size", size.as_str()), ("offset", offset.as_str())];
+        let (from, to);
+        match list {
+            AlbumListType::ByYear { from: f, to: t } => {
+                from = f.to_string();
+                to   = t.to_string();
+                params.push(("fromYear", &from));
+                params.push(("toYear", &to));
             }
+            AlbumListType::ByGenre(genre) => params.push(("genre", genre)),
+            _ => {}
+        }
+        self.push_music_folders(&mut params);
+        let body = self.fetch("getAlbumList2", &params).await?;
+        match body.response.content {
+            ContentType::AlbumList { album_list2 } => Ok(album_list2.album),
+            _ => anyhow::bail!("Unexpected response for album list"),
         }
-        Ok(result)
-    } else {
-        // Keine Songs gefunden
-        eprintln!("No songs found or unexpected response structure");
-        Ok(Vec::new())
     }
-}
 
-pub async fn scrobble(song_id: &str, timestamp_ms: u128, config: &Config) -> Result<()> {
-    let client     = reqwest::Client::new();
-    let mut params = build_auth_query(config);
-    params.push(("id".to_string(),         song_id.to_string()));
-    params.push(("time".to_string(),        timestamp_ms.to_string()));
-    params.push(("submission".to_string(),  "true".to_string()));
-    let response = client
-        .get(format!("{}/rest/scrobble", config.server.url))
-        .query(&params).send().await?;
-    if !response.status().is_success() {
-        eprintln!("Scrobble failed: {}", response.text().await.unwrap_or_default());
-    }
-    Ok(())
+    pub async fn get_genres(&self) -> Result<Vec<Genre>> {
+        let body = self.fetch("getGenres", &[]).await?;
+        match body.response.content {
+            ContentType::Genres { genres } => Ok(genres.genre),
+            _ => anyhow::bail!("Unexpected response for genres"),
+        }
+    }
+
+    /// One page of `getSongsByGenre` (`count` ≤ 500).
+    pub async fn get_songs_by_genre(&self, genre: &str, count: u32, offset: u32) -> Result<Vec<Song>> {
+        let count  = count.to_string();
+        let offset = offset.to_string();
+        let mut params = vec![("genre", genre), ("count", count.as_str()), ("offset", offset.as_str())];
+        self.push_music_folders(&mut params);
+        let body = self.fetch("getSongsByGenre", &params).await?;
+        match body.response.content {
+            ContentType::SongsByGenre { songs_by_genre } => Ok(songs_by_genre.song),
+            _ => anyhow::bail!("Unexpected response for songs by genre"),
+        }
+    }
+
+    /// Biography and up to `count` similar artists from the library.
+    pub async fn get_artist_info(&self, artist_id: &str, count: u32) -> Result<ArtistInfo> {
+        let count = count.to_string();
+        let body  = self.fetch("getArtistInfo2", &[("id", artist_id), ("count", &count)]).await?;
+        match body.response.content {
+            ContentType::ArtistInfo { artist_info2 } => Ok(artist_info2),
+            _ => Ok(ArtistInfo::default()),
+        }
+    }
+
+    /// Most played songs of an artist (by name, as the API wants it).
+    pub async fn get_top_songs(&self, artist: &str, count: u32) -> Result<Vec<Song>> {
+        let count = count.to_string();
+        let body  = self.fetch("getTopSongs", &[("artist", artist), ("count", &count)]).await?;
+        match body.response.content {
+            ContentType::TopSongs { top_songs } => Ok(top_songs.song),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Songs similar to an artist (and their similar artists), for song radio.
+    pub async fn get_similar_songs(&self, artist_id: &str, count: u32) -> Result<Vec<Song>> {
+        let count = count.to_string();
+        let body  = self.fetch("getSimilarSongs2", &[("id", artist_id), ("count", &count)]).await?;
+        match body.response.content {
+            ContentType::SimilarSongs { similar_songs2 } => Ok(similar_songs2.song),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Podcast channels with their episodes.
+    pub async fn get_podcasts(&self) -> Result<Vec<PodcastChannel>> {
+        let body = self.fetch("getPodcasts", &[("includeEpisodes", "true")]).await?;
+        match body.response.content {
+            ContentType::Podcasts { podcasts } => Ok(podcasts.channel),
+            _ => anyhow::bail!("Unexpected response for podcasts"),
+        }
+    }
+
+    pub async fn get_newest_podcasts(&self, count: u32) -> Result<Vec<PodcastEpisode>> {
+        let count = count.to_string();
+        let body  = self.fetch("getNewestPodcasts", &[("count", &count)]).await?;
+        match body.response.content {
+            ContentType::NewestPodcasts { newest_podcasts } => Ok(newest_podcasts.episode),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    pub async fn create_podcast_channel(&self, url: &str) -> Result<()> {
+        self.get("createPodcastChannel", &[("url", url)]).await?;
+        Ok(())
+    }
+
+    /// Asks the server to download an episode; it does so in the background.
+    pub async fn download_podcast_episode(&self, episode_id: &str) -> Result<()> {
+        self.get("downloadPodcastEpisode", &[("id", episode_id)]).await?;
+        Ok(())
+    }
+
+    pub async fn delete_podcast_episode(&self, episode_id: &str) -> Result<()> {
+        self.get("deletePodcastEpisode", &[("id", episode_id)]).await?;
+        Ok(())
+    }
+
+    /// Saves a position (ms) in a song or episode, replacing an older one.
+    pub async fn create_bookmark(&self, id: &str, position_ms: u64) -> Result<()> {
+        let position = position_ms.to_string();
+        self.get("createBookmark", &[("id", id), ("position", &position)]).await?;
+        Ok(())
+    }
+
+    pub async fn delete_bookmark(&self, id: &str) -> Result<()> {
+        self.get("deleteBookmark", &[("id", id)]).await?;
+        Ok(())
+    }
+
+    pub async fn get_bookmarks(&self) -> Result<Vec<Bookmark>> {
+        let body = self.fetch("getBookmarks", &[]).await?;
+        match body.response.content {
+            ContentType::Bookmarks { bookmarks } => Ok(bookmarks.bookmark),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Public link for a song, album or playlist id; the server returns the new share.
+    pub async fn create_share(&self, id: &str, description: &str) -> Result<Share> {
+        let body = self.get("createShare", &[("id", id), ("description", description)]).await?;
+        match body.response.content {
+            ContentType::Shares { shares } => shares.share.into_iter().next()
+                .ok_or_else(|| anyhow::anyhow!("Server returned no share")),
+            _ => anyhow::bail!("Unexpected response for createShare"),
+        }
+    }
+
+    pub async fn get_shares(&self) -> Result<Vec<Share>> {
+        let body = self.fetch("getShares", &[]).await?;
+        match body.response.content {
+            ContentType::Shares { shares } => Ok(shares.share),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    pub async fn delete_share(&self, id: &str) -> Result<()> {
+        self.get("deleteShare", &[("id", id)]).await?;
+        Ok(())
+    }
+
+    pub async fn get_internet_radio_stations(&self) -> Result<Vec<InternetRadioStation>> {
+        let body = self.fetch("getInternetRadioStations", &[]).await?;
+        match body.response.content {
+            ContentType::InternetRadioStations { internet_radio_stations } =>
+                Ok(internet_radio_stations.internet_radio_station),
+            _ => anyhow::bail!("Unexpected response for internet radio stations"),
+        }
+    }
+
+    pub async fn get_random_songs(&self, count: u16) -> Result<Vec<Song>> {
+        let size = count.to_string();
+        let mut params = vec![("size", size.as_str())];
+        self.push_music_folders(&mut params);
+        let body = self.fetch("getRandomSongs", &params).await?;
+        match body.response.content {
+            ContentType::RandomSongs { random_songs } => Ok(random_songs.song),
+            other => {
+                eprintln!("Unexpected getRandomSongs response: {:#?}", other);
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    /// `search3` – artists, albums and songs matching `query`, paged per type.
+    pub async fn search(&self, query: &str, page: &SearchPage) -> Result<SearchResult> {
+        let numbers = [
+            page.artist_count, page.artist_offset,
+            page.album_count,  page.album_offset,
+            page.song_count,   page.song_offset,
+        ].map(|n| n.to_string());
+        let mut params = vec![
+            ("query",        query),
+            ("artistCount",  numbers[0].as_str()), ("artistOffset", numbers[1].as_str()),
+            ("albumCount",   numbers[2].as_str()), ("albumOffset",  numbers[3].as_str()),
+            ("songCount",    numbers[4].as_str()), ("songOffset",   numbers[5].as_str()),
+        ];
+        self.push_music_folders(&mut params);
+        let body = self.fetch("search3", &params).await?;
+        match body.response.content {
+            ContentType::SearchResults { search_result3 } => Ok(search_result3),
+            _ => Ok(SearchResult::default()),
+        }
+    }
+
+    pub async fn scrobble(&self, song_id: &str, timestamp_ms: u128) -> Result<()> {
+        let time = timestamp_ms.to_string();
+        self.get("scrobble", &[("id", song_id), ("time", &time), ("submission", "true")]).await?;
+        Ok(())
+    }
+
+    /// `scrobble` with `submission=false` – shows the track in other users' "now playing".
+    pub async fn announce_now_playing(&self, song_id: &str) -> Result<()> {
+        self.get("scrobble", &[("id", song_id), ("submission", "false")]).await?;
+        Ok(())
+    }
+
+    /// Chat messages newer than `since` (ms since the epoch), oldest first.
+    pub async fn get_chat_messages(&self, since: Option<u64>) -> Result<Vec<ChatMessage>> {
+        let since = since.map(|s| s.to_string());
+        let mut params = Vec::new();
+        if let Some(since) = &since {
+            params.push(("since", since.as_str()));
+        }
+        let body = self.fetch("getChatMessages", &params).await?;
+        match body.response.content {
+            ContentType::ChatMessages { chat_messages } => {
+                let mut messages = chat_messages.chat_message;
+                messages.sort_by_key(|m| m.time);
+                Ok(messages)
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    pub async fn add_chat_message(&self, message: &str) -> Result<()> {
+        self.get("addChatMessage", &[("message", message)]).await?;
+        Ok(())
+    }
+
+    pub async fn get_now_playing(&self) -> Result<Vec<NowPlayingEntry>> {
+        let body = self.fetch("getNowPlaying", &[]).await?;
+        match body.response.content {
+            ContentType::NowPlaying { now_playing } => Ok(now_playing.entry),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    pub async fn star(&self, target: StarTarget, id: &str) -> Result<()> {
+        self.get("star", &[(target.param(), id)]).await?;
+        Ok(())
+    }
+
+    pub async fn unstar(&self, target: StarTarget, id: &str) -> Result<()> {
+        self.get("unstar", &[(target.param(), id)]).await?;
+        Ok(())
+    }
+
+    /// Rates a song or album 1–5; 0 removes the rating.
+    pub async fn set_rating(&self, id: &str, rating: u8) -> Result<()> {
+        let rating = rating.min(5).to_string();
+        self.get("setRating", &[("id", id), ("rating", &rating)]).await?;
+        Ok(())
+    }
+
+    /// Stores the queue on the server so other clients can continue it.
+    pub async fn save_play_queue(&self, song_ids: &[String], current: &str, position_ms: u64) -> Result<()> {
+        let position = position_ms.to_string();
+        let mut params: Vec<(&str, &str)> = song_ids.iter().map(|id| ("id", id.as_str())).collect();
+        params.push(("current", current));
+        params.push(("position", &position));
+        self.get("savePlayQueue", &params).await?;
+        Ok(())
+    }
+
+    /// The saved queue, `None` if there is none.
+    pub async fn get_play_queue(&self) -> Result<Option<PlayQueue>> {
+        let body = self.fetch("getPlayQueue", &[]).await?;
+        match body.response.content {
+            ContentType::PlayQueue { play_queue } => Ok(Some(play_queue)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Plain lyrics by artist and title; `None` if the server has none.
+    pub async fn get_lyrics(&self, artist: &str, title: &str) -> Result<Option<String>> {
+        let body = self.fetch("getLyrics", &[("artist", artist), ("title", title)]).await?;
+        match body.response.content {
+            ContentType::Lyrics { lyrics } if !lyrics.value.trim().is_empty() => Ok(Some(lyrics.value)),
+            _ => Ok(None),
+        }
+    }
+
+    /// OpenSubsonic lyrics (synced where available) of one song.
+    pub async fn get_lyrics_by_song_id(&self, song_id: &str) -> Result<Vec<StructuredLyrics>> {
+        let body = self.fetch("getLyricsBySongId", &[("id", song_id)]).await?;
+        match body.response.content {
+            ContentType::LyricsList { lyrics_list } => Ok(lyrics_list.structured_lyrics),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Starred artists, albums and songs (ID3 tags).
+    pub async fn get_starred(&self) -> Result<SearchResult> {
+        let body = self.fetch("getStarred2", &[]).await?;
+        match body.response.content {
+            ContentType::Starred { starred2 } => Ok(starred2),
+            _ => anyhow::bail!("Unexpected response for starred items"),
+        }
+    }
+
+    pub async fn get_cover_art(&self, cover_id: &str) -> Result<Vec<u8>> {
+        let response = self.send("getCoverArt", &[("id", cover_id)], Retry::Transient).await?;
+        let is_json  = response.headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|ct| ct.contains("json"));
+        let bytes = response.bytes().await?;
+        if is_json {
+            // Failed getCoverArt calls answer with an error envelope instead of image data
+            let body: SubsonicResponse = serde_json::from_slice(&bytes)?;
+            if let Some(err) = body.response.error {
+                return Err(SubsonicError::from(err).into());
+            }
+        }
+        Ok(bytes.to_vec())
+    }
 }
 
-pub async fn star_song(song_id: &str, config: &Config) -> Result<()> {
-    let client = reqwest::Client::new();
-    let mut params = build_auth_query(config);
-    params.push(("id".to_string(), song_id.to_string()));
-    
-    let response = client
-        .get(format!("{}/rest/star", config.server.url))
-        .query(&params)
-        .send()
-        .await?;
-    
-    if !response.status().is_success() {
-        let error_text = response.text().await.unwrap_or_default();
-        eprintln!("Star song failed: {}", error_text);
-        anyhow::bail!("Failed to star song: {}", error_text);
-    }
-    
-    Ok(())
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use crate::api::{mock::MockServer, models::{PlaylistUpdate, StarTarget}, SubsonicError};
+
+    #[tokio::test]
+    async fn get_artists_flattens_index_groups() {
+        let mock    = MockServer::start().await;
+        let artists = mock.client().get_artists().await.unwrap();
+        let names: Vec<_> = artists.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["Abba", "Beatles"]);
+
+        let call = &mock.calls_to("getArtists")[0];
+        assert_eq!(call.param("u"), Some("tester"));
+        assert_eq!(call.param("f"), Some("json"));
+    }
+
+    #[tokio::test]
+    async fn empty_library_has_no_artists() {
+        let mock = MockServer::start().await;
+        mock.respond_with("getArtists", serde_json::json!({"artists": {"ignoredArticles": ""}}));
+        assert!(mock.client().get_artists().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn album_songs_are_requested_by_id() {
+        let mock  = MockServer::start().await;
+        let songs = mock.client().get_album_songs("1").await.unwrap();
+        assert_eq!(songs.len(), 2);
+        assert_eq!(mock.calls_to("getAlbum")[0].param("id"), Some("1"));
+    }
+
+    #[tokio::test]
+    async fn failed_status_becomes_typed_error() {
+        let mock = MockServer::start().await;
+        mock.fail_with("getArtists", 40, "Wrong username or password");
+        let err = mock.client().get_artists().await.unwrap_err();
+        assert!(matches!(err.downcast_ref::<SubsonicError>(), Some(SubsonicError::WrongCredentials(_))));
+
+        mock.fail_with("star", 70, "Song not found");
+        let err = mock.client().star(StarTarget::Song, "nope").await.unwrap_err();
+        assert_eq!(err.downcast_ref::<SubsonicError>().map(|e| e.code()), Some(70));
+    }
+
+    #[tokio::test]
+    async fn playlist_edits_repeat_parameters() {
+        let mock   = MockServer::start().await;
+        let client = mock.client();
+        client.create_playlist("Mix", &["s-1".to_string(), "s-2".to_string()]).await.unwrap();
+        client.update_playlist("pl-1", &PlaylistUpdate {
+            name: Some("Party!".to_string()),
+            song_ids_to_add: vec!["s-4".to_string(), "s-5".to_string()],
+            song_indexes_to_remove: vec![0],
+            ..PlaylistUpdate::default()
+        }).await.unwrap();
+
+        let create = &mock.calls_to("createPlaylist")[0];
+        let ids: Vec<_> = create.params.iter().filter(|(k, _)| k == "songId").map(|(_, v)| v.as_str()).collect();
+        assert_eq!(ids, ["s-1", "s-2"]);
+        let update = &mock.calls_to("updatePlaylist")[0];
+        assert_eq!(update.param("name"), Some("Party!"));
+        assert_eq!(update.params.iter().filter(|(k, _)| k == "songIdToAdd").count(), 2);
+        assert_eq!(update.param("songIndexToRemove"), Some("0"));
+        assert_eq!(update.param("comment"), None);
+    }
+
+    #[tokio::test]
+    async fn transient_server_errors_are_retried() {
+        let mock = MockServer::start().await;
+        mock.fail_http("getPlaylists", 503, 1);
+        let playlists = mock.client().get_playlists().await.unwrap();
+        assert_eq!(playlists.len(), 1);
+        assert_eq!(mock.calls_to("getPlaylists").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn calls_with_side_effects_are_not_retried_on_server_errors() {
+        let mock = MockServer::start().await;
+        mock.fail_http("createShare", 503, 1);
+        assert!(mock.client().create_share("al-1", "Album").await.is_err());
+        assert_eq!(mock.calls_to("createShare").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn cover_art_returns_image_bytes() {
+        let mock  = MockServer::start().await;
+        let bytes = mock.client().get_cover_art("al-1").await.unwrap();
+        assert!(crate::cover::image_to_ascii(&bytes, 10).is_ok());
+    }
+}
@@ -0,0 +1,130 @@
+use std::fmt;
+
+use crate::api::models::ApiError;
+
+/// Typed `status="failed"` response from the server.
+///
+/// The variants mirror the error codes of the Subsonic API (plus the
+/// OpenSubsonic auth codes 41–44); the server's own message is kept so it
+/// can be shown next to our description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubsonicError {
+    /// 0 – A generic error.
+    Generic(String),
+    /// 10 – Required parameter is missing.
+    MissingParameter(String),
+    /// 20 – Incompatible REST protocol version. Client must upgrade.
+    ClientTooOld(String),
+    /// 30 – Incompatible REST protocol version. Server must upgrade.
+    ServerTooOld(String),
+    /// 40 – Wrong username or password.
+    WrongCredentials(String),
+    /// 41 – Token authentication not supported for LDAP users.
+    TokenAuthNotSupported(String),
+    /// 42 – Provided authentication mechanism not supported.
+    AuthNotSupported(String),
+    /// 43 – Multiple conflicting authentication mechanisms provided.
+    ConflictingAuth(String),
+    /// 44 – Invalid API key.
+    InvalidApiKey(String),
+    /// 50 – User is not authorized for the given operation.
+    NotAuthorized(String),
+    /// 60 – The trial period for the Subsonic server is over.
+    TrialExpired(String),
+    /// 70 – The requested data was not found.
+    NotFound(String),
+    /// Any code not covered above.
+    Unknown { code: i32, message: String },
+}
+
+impl SubsonicError {
+    pub fn new(code: i32, message: String) -> Self {
+        match code {
+            0  => SubsonicError::Generic(message),
+            10 => SubsonicError::MissingParameter(message),
+            20 => SubsonicError::ClientTooOld(message),
+            30 => SubsonicError::ServerTooOld(message),
+            40 => SubsonicError::WrongCredentials(message),
+            41 => SubsonicError::TokenAuthNotSupported(message),
+            42 => SubsonicError::AuthNotSupported(message),
+            43 => SubsonicError::ConflictingAuth(message),
+            44 => SubsonicError::InvalidApiKey(message),
+            50 => SubsonicError::NotAuthorized(message),
+            60 => SubsonicError::TrialExpired(message),
+            70 => SubsonicError::NotFound(message),
+            _  => SubsonicError::Unknown { code, message },
+        }
+    }
+
+    pub fn code(&self) -> i32 {
+        match self {
+            SubsonicError::Generic(_)               => 0,
+            SubsonicError::MissingParameter(_)      => 10,
+            SubsonicError::ClientTooOld(_)          => 20,
+            SubsonicError::ServerTooOld(_)          => 30,
+            SubsonicError::WrongCredentials(_)      => 40,
+            SubsonicError::TokenAuthNotSupported(_) => 41,
+            SubsonicError::AuthNotSupported(_)      => 42,
+            SubsonicError::ConflictingAuth(_)       => 43,
+            SubsonicError::InvalidApiKey(_)         => 44,
+            SubsonicError::NotAuthorized(_)         => 50,
+            SubsonicError::TrialExpired(_)          => 60,
+            SubsonicError::NotFound(_)              => 70,
+            SubsonicError::Unknown { code, .. }     => *code,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            SubsonicError::Generic(m)
+            | SubsonicError::MissingParameter(m)
+            | SubsonicError::ClientTooOld(m)
+            | SubsonicError::ServerTooOld(m)
+            | SubsonicError::WrongCredentials(m)
+            | SubsonicError::TokenAuthNotSupported(m)
+            | SubsonicError::AuthNotSupported(m)
+            | SubsonicError::ConflictingAuth(m)
+            | SubsonicError::InvalidApiKey(m)
+            | SubsonicError::NotAuthorized(m)
+            | SubsonicError::TrialExpired(m)
+            | SubsonicError::NotFound(m)            => m,
+            SubsonicError::Unknown { message, .. } => message,
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            SubsonicError::Generic(_)               => "Server error",
+            SubsonicError::MissingParameter(_)      => "Missing parameter",
+            SubsonicError::ClientTooOld(_)          => "Client too old for this server",
+            SubsonicError::ServerTooOld(_)          => "Server too old for this client",
+            SubsonicError::WrongCredentials(_)      => "Wrong username or password",
+            SubsonicError::TokenAuthNotSupported(_) => "Token auth not supported for this user",
+            SubsonicError::AuthNotSupported(_)      => "Auth mechanism not supported",
+            SubsonicError::ConflictingAuth(_)       => "Conflicting auth mechanisms",
+            SubsonicError::InvalidApiKey(_)         => "Invalid API key",
+            SubsonicError::NotAuthorized(_)         => "Not authorized",
+            SubsonicError::TrialExpired(_)          => "Server trial expired",
+            SubsonicError::NotFound(_)              => "Not found",
+            SubsonicError::Unknown { .. }           => "Unknown server error",
+        }
+    }
+}
+
+impl From<ApiError> for SubsonicError {
+    fn from(e: ApiError) -> Self {
+        SubsonicError::new(e.code, e.message.unwrap_or_default())
+    }
+}
+
+impl fmt::Display for SubsonicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.message().is_empty() {
+            write!(f, "{} ({})", self.description(), self.code())
+        } else {
+            write!(f, "{} ({}): {}", self.description(), self.code(), self.message())
+        }
+    }
+}
+
+impl std::error::Error for SubsonicError {}
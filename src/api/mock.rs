@@ -0,0 +1,464 @@
+//! Local stand-in for a Subsonic server, used by the test suite.
+//!
+//! Serves canned JSON for the endpoints TerminalDrome uses and records every
+//! call (endpoint + decoded query/form parameters), so `SubsonicClient` and
+//! `App` can be exercised end-to-end without a live Navidrome.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io::Cursor,
+    sync::{Arc, Mutex},
+};
+
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+use crate::api::SubsonicClient;
+use crate::config::Config;
+
+#[derive(Debug, Clone)]
+pub struct MockCall {
+    pub method:   String,
+    pub endpoint: String,
+    pub params:   Vec<(String, String)>,
+    pub headers:  Vec<(String, String)>,
+}
+
+impl MockCall {
+    pub fn param(&self, key: &str) -> Option<&str> {
+        self.params.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(k, _)| k.eq_ignore_ascii_case(name)).map(|(_, v)| v.as_str())
+    }
+}
+
+#[derive(Clone)]
+enum Reply {
+    Json(Value),
+    Status(u16),
+}
+
+#[derive(Default)]
+struct MockState {
+    calls:  Vec<MockCall>,
+    /// One-shot replies, consumed in order before `sticky` is consulted.
+    queued: HashMap<String, VecDeque<Reply>>,
+    sticky: HashMap<String, Reply>,
+}
+
+pub struct MockServer {
+    pub url: String,
+    state:   Arc<Mutex<MockState>>,
+    handle:  JoinHandle<()>,
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) { self.handle.abort(); }
+}
+
+impl MockServer {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind mock server");
+        let url      = format!("http://{}", listener.local_addr().unwrap());
+        let state    = Arc::new(Mutex::new(MockState::default()));
+        let state_c  = state.clone();
+        let handle   = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let state = state_c.clone();
+                tokio::spawn(async move { let _ = handle_connection(stream, state).await; });
+            }
+        });
+        Self { url, state, handle }
+    }
+
+    pub fn config(&self) -> Config {
+        self.config_with("")
+    }
+
+    /// Mock config with extra TOML appended to the `[server]` table.
+    pub fn config_with(&self, extra: &str) -> Config {
+        toml::from_str(&format!(
+            "[server]\nurl = \"{}\"\nusername = \"tester\"\npassword = \"secret\"\ntimeout_secs = 5\nretries = 1\n{}",
+            self.url, extra,
+        )).expect("mock config")
+    }
+
+    /// Removes an endpoint from the server (it will answer 404).
+    pub fn disable(&self, endpoint: &str) {
+        self.state.lock().unwrap().sticky.insert(endpoint.to_string(), Reply::Status(404));
+    }
+
+    pub fn client(&self) -> SubsonicClient {
+        SubsonicClient::new(self.config()).expect("mock client")
+    }
+
+    /// All recorded calls, in order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state.lock().unwrap().calls.clone()
+    }
+
+    pub fn calls_to(&self, endpoint: &str) -> Vec<MockCall> {
+        self.calls().into_iter().filter(|c| c.endpoint == endpoint).collect()
+    }
+
+    /// Replaces the canned payload of `endpoint`. The object is merged into a
+    /// `status="ok"` envelope.
+    pub fn respond_with(&self, endpoint: &str, payload: Value) {
+        self.state.lock().unwrap().sticky
+            .insert(endpoint.to_string(), Reply::Json(ok_envelope(payload)));
+    }
+
+    /// Makes `endpoint` answer with a `status="failed"` error envelope.
+    pub fn fail_with(&self, endpoint: &str, code: i32, message: &str) {
+        let body = json!({"subsonic-response": {
+            "status": "failed", "version": "1.16.1",
+            "error": {"code": code, "message": message},
+        }});
+        self.state.lock().unwrap().sticky.insert(endpoint.to_string(), Reply::Json(body));
+    }
+
+    /// Makes the next `times` calls to `endpoint` fail with HTTP `status`.
+    pub fn fail_http(&self, endpoint: &str, status: u16, times: usize) {
+        self.state.lock().unwrap().queued
+            .insert(endpoint.to_string(), vec![Reply::Status(status); times].into());
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, state: Arc<Mutex<MockState>>) -> std::io::Result<()> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 { return Ok(()); }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") { break pos + 4; }
+    };
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let headers: Vec<(String, String)> = head.lines()
+        .skip(1)
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect();
+    let content_length = headers.iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 { break; }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let mut request_line = head.lines().next().unwrap_or("").split_whitespace();
+    let method = request_line.next().unwrap_or("GET").to_string();
+    let target = request_line.next().unwrap_or("/");
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let endpoint = path.trim_start_matches("/rest/").trim_end_matches(".view").to_string();
+    let mut params = parse_query(query);
+    let body_end = (header_end + content_length).min(buf.len());
+    params.extend(parse_query(&String::from_utf8_lossy(&buf[header_end..body_end])));
+
+    let reply = {
+        let mut st = state.lock().unwrap();
+        st.calls.push(MockCall { method, endpoint: endpoint.clone(), params: params.clone(), headers });
+        match st.queued.get_mut(&endpoint).and_then(|q| q.pop_front()) {
+            Some(reply) => Some(reply),
+            None        => st.sticky.get(&endpoint).cloned(),
+        }
+    };
+
+    let (status, content_type, body) = match reply {
+        Some(Reply::Status(code)) => (code, "text/plain", Vec::new()),
+        Some(Reply::Json(v))      => (200, "application/json", v.to_string().into_bytes()),
+        None if endpoint == "getCoverArt" => (200, "image/png", cover_png()),
+        None => match canned(&endpoint, &params) {
+            Some(v) => (200, "application/json", ok_envelope(v).to_string().into_bytes()),
+            None    => (404, "text/plain", Vec::new()),
+        },
+    };
+
+    let header = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status, content_type, body.len(),
+    );
+    stream.write_all(header.as_bytes()).await?;
+    stream.write_all(&body).await?;
+    stream.shutdown().await
+}
+
+fn parse_query(q: &str) -> Vec<(String, String)> {
+    q.split('&')
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let (k, v) = p.split_once('=').unwrap_or((p, ""));
+            (percent_decode(k), percent_decode(v))
+        })
+        .collect()
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(b) => { out.push(b); i += 2; }
+                    Err(_) => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+fn ok_envelope(payload: Value) -> Value {
+    let mut inner = json!({
+        "status": "ok", "version": "1.16.1",
+        "type": "mock", "serverVersion": "0.1.0", "openSubsonic": true,
+    });
+    if let (Some(obj), Value::Object(extra)) = (inner.as_object_mut(), payload) {
+        obj.extend(extra);
+    }
+    json!({"subsonic-response": inner})
+}
+
+fn cover_png() -> Vec<u8> {
+    let img = image::RgbImage::from_fn(8, 8, |x, y| image::Rgb([(x * 32) as u8, (y * 32) as u8, 128]));
+    let mut out = Cursor::new(Vec::new());
+    image::DynamicImage::ImageRgb8(img)
+        .write_to(&mut out, image::ImageOutputFormat::Png)
+        .expect("encode mock cover");
+    out.into_inner()
+}
+
+pub fn song_json(id: &str, title: &str, album_id: &str) -> Value {
+    json!({
+        "id": id, "title": title, "duration": 180, "track": 1,
+        "artist": "Mock Artist", "album": "Mock Album", "albumId": album_id,
+        "artistId": "ar-1", "coverArt": format!("al-{}", album_id),
+    })
+}
+
+/// Canned payloads (without envelope) for the endpoints the app uses.
+fn canned(endpoint: &str, params: &[(String, String)]) -> Option<Value> {
+    let param = |k: &str| params.iter().find(|(pk, _)| pk == k).map(|(_, v)| v.clone()).unwrap_or_default();
+    let payload = match endpoint {
+        "ping" => json!({}),
+        "getOpenSubsonicExtensions" => json!({"openSubsonicExtensions": [
+            {"name": "formPost", "versions": [1]},
+            {"name": "songLyrics", "versions": [1]},
+            {"name": "transcodeOffset", "versions": [1]},
+        ]}),
+        "getArtists" => json!({"artists": {"ignoredArticles": "The", "index": [
+            {"name": "A", "artist": [{"id": "ar-1", "name": "Abba", "albumCount": 2}]},
+            {"name": "B", "artist": [{"id": "ar-2", "name": "Beatles", "albumCount": 1}]},
+        ]}}),
+        "getArtist" => json!({"artist": {"id": param("id"), "name": "Abba", "album": [
+            {"id": "1", "name": "Arrival", "artist": "Abba", "coverArt": "al-1", "year": 1976, "songCount": 2},
+            {"id": "2", "name": "Voulez-Vous", "artist": "Abba", "coverArt": "al-2", "year": 1979, "songCount": 1},
+        ]}}),
+        "getAlbum" => {
+            let id = param("id");
+            json!({"album": {"id": id, "name": "Arrival", "artist": "Abba", "songCount": 2, "song": [
+                song_json("s-1", "Dancing Queen", &id),
+                song_json("s-2", "Money, Money, Money", &id),
+            ]}})
+        }
+        "getPlaylists" => json!({"playlists": {"playlist": [
+            {"id": "pl-1", "name": "Party", "songCount": 2, "duration": 360},
+        ]}}),
+        "getPlaylist" => json!({"playlist": {"id": param("id"), "name": "Party", "songCount": 2, "entry": [
+            song_json("s-1", "Dancing Queen", "1"),
+            song_json("s-3", "Help!", "3"),
+        ]}}),
+        "getRandomSongs" => {
+            let size = param("size").parse::<usize>().unwrap_or(10);
+            let songs: Vec<Value> = (0..size)
+                .map(|i| song_json(&format!("r-{}", i), &format!("Random {}", i), "9"))
+                .collect();
+            json!({"randomSongs": {"song": songs}})
+        }
+        "search3" => {
+            // 1 artist, 1 album and 75 songs, honouring the per-type count/offset
+            let page = |kind: &str, total: usize| {
+                let offset = param(&format!("{}Offset", kind)).parse::<usize>().unwrap_or(0);
+                let count  = param(&format!("{}Count", kind)).parse::<usize>().unwrap_or(20);
+                offset.min(total)..(offset + count).min(total)
+            };
+            let artists: Vec<Value> = page("artist", 1).map(|_| json!({"id": "ar-1", "name": "Abba"})).collect();
+            let albums: Vec<Value>  = page("album", 1)
+                .map(|_| json!({"id": "1", "name": "Arrival", "artist": "Abba", "artistId": "ar-1", "songCount": 2}))
+                .collect();
+            let songs: Vec<Value>   = page("song", 75)
+                .map(|i| song_json(&format!("hit-{}", i), &format!("Dancing Queen {}", i), "1"))
+                .collect();
+            json!({"searchResult3": {"artist": artists, "album": albums, "song": songs}})
+        }
+        "getAlbumList2" => {
+            // 120 albums in total, so paging runs out on the third page of 50
+            let offset = param("offset").parse::<usize>().unwrap_or(0);
+            let size   = param("size").parse::<usize>().unwrap_or(10);
+            let albums: Vec<Value> = (offset..(offset + size).min(120))
+                .map(|i| json!({"id": format!("al-{}", i), "name": format!("Album {}", i),
+                                "artist": "Mock Artist", "year": 1990 + (i % 10), "songCount": 10,
+                                "created": "2024-05-01T12:00:00Z"}))
+                .collect();
+            json!({"albumList2": {"album": albums}})
+        }
+        "getGenres" => json!({"genres": {"genre": [
+            {"value": "Rock", "songCount": 3, "albumCount": 2},
+            {"value": "Pop",  "songCount": 2, "albumCount": 1},
+        ]}}),
+        "getSongsByGenre" => {
+            let genre = param("genre");
+            json!({"songsByGenre": {"song": [
+                song_json("g-1", &format!("{} Song 1", genre), "1"),
+                song_json("g-2", &format!("{} Song 2", genre), "2"),
+            ]}})
+        }
+        "getStarred2" => {
+            let starred = |mut v: Value| { v["starred"] = json!("2024-05-01T12:00:00Z"); v };
+            json!({"starred2": {
+                "artist": [starred(json!({"id": "ar-2", "name": "Beatles"}))],
+                "album":  [starred(json!({"id": "3", "name": "Help!", "artist": "Beatles", "artistId": "ar-2"}))],
+                "song":   [starred(song_json("s-3", "Help!", "3")), starred(song_json("s-1", "Dancing Queen", "1"))],
+            }})
+        }
+        "getPlayQueue" => json!({"playQueue": {
+            "current": "s-2", "position": 95_000, "changedBy": "phone",
+            "entry": [song_json("s-1", "Dancing Queen", "1"), song_json("s-2", "Money, Money, Money", "1")],
+        }}),
+        "savePlayQueue" => json!({}),
+        "getArtistInfo2" => match param("id").as_str() {
+            "ar-1" => json!({"artistInfo2": {
+                "biography": "Swedish pop group. <a target='_blank' href=\"https://last.fm/abba\">Read more on Last.fm</a>",
+                "largeImageUrl": "https://example.org/abba.jpg",
+                "similarArtist": [{"id": "ar-2", "name": "Beatles"}],
+            }}),
+            _ => json!({"artistInfo2": {}}),
+        },
+        "getTopSongs" => json!({"topSongs": {"song": [
+            song_json("s-2", "Money, Money, Money", "1"),
+            song_json("s-1", "Dancing Queen", "1"),
+        ]}}),
+        "getSimilarSongs2" => match param("id").as_str() {
+            "ar-1" => json!({"similarSongs2": {"song": [
+                song_json("sim-1", "Similar 1", "5"),
+                song_json("sim-2", "Similar 2", "5"),
+                song_json("sim-3", "Similar 3", "6"),
+            ]}}),
+            _ => json!({"similarSongs2": {}}),
+        },
+        "getPodcasts" => json!({"podcasts": {"channel": [
+            {"id": "pc-1", "url": "https://example.org/feed.xml", "title": "Tech Talk", "status": "completed", "episode": [
+                {"id": "ep-1", "streamId": "pe-1", "channelId": "pc-1", "title": "Episode 1", "status": "completed",
+                 "duration": 3600, "album": "Tech Talk", "publishDate": "2026-09-01T06:00:00Z"},
+                {"id": "ep-2", "channelId": "pc-1", "title": "Episode 2", "status": "new",
+                 "publishDate": "2026-09-08T06:00:00Z"},
+                {"id": "ep-3", "channelId": "pc-1", "title": "Episode 3", "status": "downloading"},
+            ]},
+        ]}}),
+        "getNewestPodcasts" => json!({"newestPodcasts": {"episode": [
+            {"id": "ep-2", "channelId": "pc-1", "title": "Episode 2", "status": "new"},
+        ]}}),
+        "getBookmarks" => json!({"bookmarks": {"bookmark": [
+            {"position": 1_234_000, "username": "mock", "created": "2026-09-02T10:00:00Z",
+             "changed": "2026-09-02T10:00:00Z",
+             "entry": {"id": "pe-1", "title": "Episode 1", "duration": 3600, "isDir": false}},
+            {"position": 5_400_000, "username": "mock", "comment": "Chapter 7", "changed": "2026-09-05T21:00:00Z",
+             "entry": {"id": "ab-1", "title": "The Hobbit (Audiobook)", "artist": "Tolkien", "duration": 40_000}},
+        ]}}),
+        "getIndexes" => json!({"indexes": {"lastModified": 0, "ignoredArticles": "The", "index": [
+            {"name": "A", "artist": [{"id": "dir-1", "name": "Abba"}]},
+            {"name": "U", "artist": [{"id": "dir-4", "name": "Unsorted"}]},
+        ], "child": [
+            {"id": "f-0", "title": "Loose track", "isDir": false, "duration": 60},
+        ]}}),
+        "getMusicDirectory" => match param("id").as_str() {
+            "dir-1" => json!({"directory": {"id": "dir-1", "name": "Abba", "child": [
+                {"id": "dir-2", "parent": "dir-1", "title": "Arrival", "isDir": true},
+                {"id": "dir-3", "parent": "dir-1", "title": "Gold", "isDir": true},
+                {"id": "f-1", "parent": "dir-1", "title": "Bonus", "isDir": false, "duration": 120},
+            ]}}),
+            "dir-2" => json!({"directory": {"id": "dir-2", "parent": "dir-1", "name": "Arrival", "child": [
+                song_json("s-1", "Dancing Queen", "al-1"),
+                song_json("s-2", "Money, Money, Money", "al-1"),
+            ]}}),
+            "dir-3" => json!({"directory": {"id": "dir-3", "parent": "dir-1", "name": "Gold", "child": [
+                song_json("s-3", "Waterloo", "al-2"),
+            ]}}),
+            id => json!({"directory": {"id": id, "name": "Empty", "child": []}}),
+        },
+        "getNowPlaying" => json!({"nowPlaying": {"entry": [
+            {"id": "s-3", "title": "Waterloo", "artist": "Abba", "duration": 170, "isDir": false,
+             "username": "anna", "minutesAgo": 2, "playerId": 7, "playerName": "Feishin"},
+            {"id": "s-1", "title": "Dancing Queen", "artist": "Abba", "duration": 180, "isDir": false,
+             "username": "tester", "minutesAgo": 0, "playerId": 8, "playerName": "TerminalDrome"},
+        ]}}),
+        "getChatMessages" => {
+            let since: u64 = param("since").parse().unwrap_or(0);
+            let messages: Vec<Value> = [
+                ("ben",  1_790_000_200_000u64, "Who put Abba on the party list?"),
+                ("anna", 1_790_000_100_000u64, "Morning!"),
+            ].iter()
+                .filter(|(_, time, _)| *time > since)
+                .map(|(user, time, message)| json!({"username": user, "time": time, "message": message}))
+                .collect();
+            json!({"chatMessages": {"chatMessage": messages}})
+        }
+        "getMusicFolders" => json!({"musicFolders": {"musicFolder": [
+            {"id": 1, "name": "Music"},
+            {"id": 2, "name": "Audiobooks"},
+        ]}}),
+        "createShare" => json!({"shares": {"share": [
+            {"id": "sh-2", "url": format!("https://mock.example/share/{}", param("id")),
+             "description": param("description"), "created": "2026-10-01T12:00:00Z", "visitCount": 0,
+             "entry": [{"id": param("id"), "title": "Shared", "isDir": false}]},
+        ]}}),
+        "getShares" => json!({"shares": {"share": [
+            {"id": "sh-1", "url": "https://mock.example/share/sh-1", "description": "Arrival",
+             "created": "2026-09-01T12:00:00Z", "expires": "2026-12-01T12:00:00Z", "visitCount": 3,
+             "entry": [{"id": "s-1", "title": "Dancing Queen", "isDir": false},
+                       {"id": "s-2", "title": "Money, Money, Money", "isDir": false}]},
+        ]}}),
+        "getInternetRadioStations" => json!({"internetRadioStations": {"internetRadioStation": [
+            {"id": "st-1", "name": "Radio Swiss Jazz", "streamUrl": "http://stream.srg-ssr.ch/m/rsj/mp3_128",
+             "homePageUrl": "https://www.radioswissjazz.ch"},
+            {"id": "st-2", "name": "FIP", "streamUrl": "http://icecast.radiofrance.fr/fip-midfi.mp3"},
+        ]}}),
+        "getLyricsBySongId" => match param("id").as_str() {
+            "s-1" => json!({"lyricsList": {"structuredLyrics": [
+                {"lang": "und", "synced": false, "line": [{"value": "You can dance"}]},
+                {"lang": "eng", "synced": true, "offset": 0, "line": [
+                    {"start": 0,      "value": "You can dance"},
+                    {"start": 4_000,  "value": "You can jive"},
+                    {"start": 8_000,  "value": "Having the time of your life"},
+                ]},
+            ]}}),
+            _ => json!({"lyricsList": {"structuredLyrics": []}}),
+        },
+        "getLyrics" => match param("title").as_str() {
+            "Money, Money, Money" => json!({"lyrics": {"artist": param("artist"), "title": param("title"),
+                                            "value": "I work all night\nI work all day"}}),
+            _ => json!({"lyrics": {}}),
+        },
+        "star" | "unstar" | "scrobble" | "setRating"
+        | "createPlaylist" | "updatePlaylist" | "deletePlaylist"
+        | "createPodcastChannel" | "downloadPodcastEpisode" | "deletePodcastEpisode"
+        | "createBookmark" | "deleteBookmark" | "deleteShare" | "addChatMessage" => json!({}),
+        _ => return None,
+    };
+    Some(payload)
+}
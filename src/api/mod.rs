@@ -1,9 +1,21 @@
 pub mod models;
 pub mod endpoints;
+pub mod client;
+pub mod error;
+pub mod capabilities;
+#[cfg(test)]
+pub mod mock;
 
-use crate::config::Config;
+pub use client::SubsonicClient;
+pub use error::SubsonicError;
+pub use capabilities::ServerCapabilities;
+
+use crate::config::{AuthMode, Config};
 use rand::Rng;
 
+pub const API_VERSION: &str = "1.16.1";
+pub const CLIENT_NAME: &str = "TerminalDrome";
+
 pub struct AuthParams {
     pub user:  String,
     pub token: String,
@@ -22,22 +34,104 @@ impl AuthParams {
     }
 }
 
-pub fn build_auth_query(config: &Config) -> Vec<(String, String)> {
-    let auth = AuthParams::new(config);
-    vec![
-        ("u".to_string(), auth.user),
-        ("t".to_string(), auth.token),
-        ("s".to_string(), auth.salt),
-        ("v".to_string(), "1.16.1".to_string()),
-        ("c".to_string(), "TerminalDrome".to_string()),
+/// Credential parameters for the configured `[server.auth]` mode.
+pub fn auth_credentials(config: &Config) -> Vec<(String, String)> {
+    match config.server.auth.mode {
+        AuthMode::Token => {
+            let auth = AuthParams::new(config);
+            vec![
+                ("u".to_string(), auth.user),
+                ("t".to_string(), auth.token),
+                ("s".to_string(), auth.salt),
+            ]
+        }
+        AuthMode::Password => {
+            let hex: String = config.server.password.bytes().map(|b| format!("{:02x}", b)).collect();
+            vec![
+                ("u".to_string(), config.server.username.clone()),
+                ("p".to_string(), format!("enc:{}", hex)),
+            ]
+        }
+        // apiKey must not be combined with `u` (error 43); a missing key is
+        // rejected when the config is loaded
+        AuthMode::ApiKey => vec![
+            ("apiKey".to_string(), config.server.auth.api_key.clone().unwrap_or_default()),
+        ],
+    }
+}
+
+/// Credentials plus `v`/`c`/`f`; `api_version` is the one agreed on with the server.
+pub fn build_auth_query(config: &Config, api_version: &str) -> Vec<(String, String)> {
+    let mut query = auth_credentials(config);
+    query.extend([
+        ("v".to_string(), api_version.to_string()),
+        ("c".to_string(), CLIENT_NAME.to_string()),
         ("f".to_string(), "json".to_string()),
-    ]
+    ]);
+    query
 }
 
-pub fn build_stream_url(song_id: &str, config: &Config) -> String {
-    let auth = AuthParams::new(config);
-    format!(
-        "{}/rest/stream?id={}&u={}&t={}&s={}&v=1.16.1&c=TerminalDrome&f=json",
-        config.server.url, song_id, auth.user, auth.token, auth.salt,
-    )
+pub fn build_stream_url(song_id: &str, client: &SubsonicClient) -> String {
+    let config = client.config();
+    let mut params = vec![("id".to_string(), song_id.to_string())];
+    params.extend(build_auth_query(config, client.api_version()));
+    let base = format!("{}/rest/stream", config.server.url.trim_end_matches('/'));
+    reqwest::Url::parse_with_params(&base, &params)
+        .map(|u| u.to_string())
+        .unwrap_or(base)
+}
+
+/// `[server.auth.headers]` as `Name: value` lines, the format mpv expects.
+pub fn stream_header_lines(config: &Config) -> Vec<String> {
+    config.server.auth.headers.iter().map(|(k, v)| format!("{}: {}", k, v)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mock::MockServer;
+
+    fn param<'a>(query: &'a [(String, String)], key: &str) -> Option<&'a str> {
+        query.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    #[tokio::test]
+    async fn token_auth_sends_salted_md5() {
+        let mock  = MockServer::start().await;
+        let query = build_auth_query(&mock.config(), API_VERSION);
+        let salt  = param(&query, "s").unwrap();
+        let token = format!("{:x}", md5::compute(format!("secret{}", salt)));
+        assert_eq!(param(&query, "t"), Some(token.as_str()));
+        assert_eq!(param(&query, "p"), None);
+    }
+
+    #[tokio::test]
+    async fn legacy_password_is_hex_encoded() {
+        let mock  = MockServer::start().await;
+        let query = build_auth_query(&mock.config_with("[server.auth]\nmode = \"password\"\n"), API_VERSION);
+        assert_eq!(param(&query, "u"), Some("tester"));
+        assert_eq!(param(&query, "p"), Some("enc:736563726574"));
+        assert_eq!(param(&query, "t"), None);
+    }
+
+    #[tokio::test]
+    async fn api_key_replaces_username() {
+        let mock   = MockServer::start().await;
+        let config = mock.config_with("[server.auth]\nmode = \"apikey\"\napi_key = \"k3y\"\n");
+        let client = SubsonicClient::new(config).unwrap();
+        client.get_playlists().await.unwrap();
+        let call = &mock.calls_to("getPlaylists")[0];
+        assert_eq!(call.param("apiKey"), Some("k3y"));
+        assert_eq!(call.param("u"), None);
+        assert!(build_stream_url("s-1", &client).contains("apiKey=k3y"));
+    }
+
+    #[tokio::test]
+    async fn extra_headers_reach_the_server() {
+        let mock   = MockServer::start().await;
+        let config = mock.config_with("[server.auth.headers]\nAuthorization = \"Bearer proxy-token\"\n");
+        SubsonicClient::new(config.clone()).unwrap().get_artists().await.unwrap();
+        assert_eq!(mock.calls_to("getArtists")[0].header("authorization"), Some("Bearer proxy-token"));
+        assert_eq!(stream_header_lines(&config), ["Authorization: Bearer proxy-token"]);
+    }
 }
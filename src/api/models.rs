@@ -8,10 +8,27 @@ pub struct SubsonicResponse {
 
 #[derive(Debug, Deserialize)]
 pub struct SubsonicContent {
+    pub status:  String,
+    pub version: Option<String>,
+    #[serde(rename = "type")]
+    pub server_type:    Option<String>,
+    #[serde(rename = "serverVersion")]
+    pub server_version: Option<String>,
+    #[serde(rename = "openSubsonic", default)]
+    pub open_subsonic:  bool,
+    #[serde(default)]
+    pub error:   Option<ApiError>,
     #[serde(flatten)]
     pub content: ContentType,
 }
 
+/// `error` element of a `status="failed"` response.
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    pub code:    i32,
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 #[allow(dead_code)]
@@ -19,15 +36,49 @@ pub enum ContentType {
     Artists        { artists: ArtistList },
     Albums         { artist: ArtistDetail },
     Songs          { album: AlbumDetail },
-    Directory      (MusicDirectory),
+    Directory      { directory: MusicDirectory },
+    Indexes        { indexes: Indexes },
     SearchResults  { #[serde(rename = "searchResult3")] search_result3: SearchResult },
     Playlists      { playlists: PlaylistList },
     PlaylistDetail { playlist: PlaylistSongs },
     RandomSongs    { #[serde(rename = "randomSongs")] random_songs: RandomSongList },
+    AlbumList      { #[serde(rename = "albumList2")] album_list2: AlbumList2 },
+    Genres         { genres: GenreList },
+    Starred        { starred2: SearchResult },
+    PlayQueue      { #[serde(rename = "playQueue")] play_queue: PlayQueue },
+    Lyrics         { lyrics: LyricsText },
+    LyricsList     { #[serde(rename = "lyricsList")] lyrics_list: LyricsList },
+    SongsByGenre   { #[serde(rename = "songsByGenre")] songs_by_genre: SongList },
+    ArtistInfo     { #[serde(rename = "artistInfo2")] artist_info2: ArtistInfo },
+    TopSongs       { #[serde(rename = "topSongs")] top_songs: SongList },
+    SimilarSongs   { #[serde(rename = "similarSongs2")] similar_songs2: SongList },
+    Podcasts       { podcasts: PodcastList },
+    NewestPodcasts { #[serde(rename = "newestPodcasts")] newest_podcasts: EpisodeList },
+    Bookmarks      { bookmarks: BookmarkList },
+    Shares         { shares: ShareList },
+    NowPlaying     { #[serde(rename = "nowPlaying")] now_playing: NowPlayingList },
+    ChatMessages   { #[serde(rename = "chatMessages")] chat_messages: ChatMessageList },
+    MusicFolders   { #[serde(rename = "musicFolders")] music_folders: MusicFolderList },
+    InternetRadioStations {
+        #[serde(rename = "internetRadioStations")] internet_radio_stations: InternetRadioStationList,
+    },
+    OpenSubsonicExtensions {
+        #[serde(rename = "openSubsonicExtensions")] extensions: Vec<OpenSubsonicExtension>,
+    },
+    /// Responses without payload (star, scrobble, …) and failed responses.
+    Empty          {},
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OpenSubsonicExtension {
+    pub name:     String,
+    #[serde(default)]
+    pub versions: Vec<i32>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ArtistList {
+    #[serde(default)]
     pub index: Vec<ArtistGroup>,
 }
 
@@ -41,6 +92,196 @@ pub struct ArtistGroup {
 pub struct Artist {
     pub id:   String,
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starred: Option<String>,
+    #[serde(rename = "coverArt", default, skip_serializing_if = "Option::is_none")]
+    pub cover_art: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PodcastList {
+    #[serde(default)]
+    pub channel: Vec<PodcastChannel>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PodcastChannel {
+    #[serde(default)]
+    pub url:     String,
+    pub title:   Option<String>,
+    /// `new`, `downloading`, `completed`, `error`, …
+    #[serde(default)]
+    pub status:  String,
+    #[serde(default)]
+    pub episode: Vec<PodcastEpisode>,
+}
+
+impl PodcastChannel {
+    /// Channels added by URL have no title until the server has fetched the feed.
+    pub fn display_title(&self) -> &str {
+        self.title.as_deref().unwrap_or(&self.url)
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct EpisodeList {
+    #[serde(default)]
+    pub episode: Vec<PodcastEpisode>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PodcastEpisode {
+    pub id:           String,
+    /// Media file to stream – only set once the episode is downloaded.
+    #[serde(rename = "streamId")]
+    pub stream_id:    Option<String>,
+    #[serde(default)]
+    pub title:        String,
+    pub description:  Option<String>,
+    #[serde(rename = "publishDate")]
+    pub publish_date: Option<String>,
+    /// `new`, `downloading`, `completed`, `error`, `deleted` or `skipped`.
+    #[serde(default)]
+    pub status:       String,
+    #[serde(default)]
+    pub duration:     u64,
+    pub artist:       Option<String>,
+    pub album:        Option<String>,
+    #[serde(rename = "coverArt")]
+    pub cover_art:    Option<String>,
+}
+
+impl PodcastEpisode {
+    /// Queue entry for a downloaded episode.
+    pub fn to_song(&self) -> Option<Song> {
+        Some(Song {
+            id:        self.stream_id.clone()?,
+            title:     self.title.clone(),
+            duration:  self.duration,
+            artist:    self.artist.clone(),
+            album:     self.album.clone(),
+            cover_art: self.cover_art.clone(),
+            ..Song::default()
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct BookmarkList {
+    #[serde(default)]
+    pub bookmark: Vec<Bookmark>,
+}
+
+/// Saved position in a song or episode (`createBookmark`).
+#[derive(Debug, Deserialize, Clone)]
+pub struct Bookmark {
+    /// Milliseconds
+    pub position: u64,
+    pub comment:  Option<String>,
+    pub changed:  Option<String>,
+    pub entry:    Song,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct NowPlayingList {
+    #[serde(default)]
+    pub entry: Vec<NowPlayingEntry>,
+}
+
+/// What a user on the server is listening to (`getNowPlaying`).
+#[derive(Debug, Deserialize, Clone)]
+pub struct NowPlayingEntry {
+    #[serde(flatten)]
+    pub song:        Song,
+    pub username:    String,
+    #[serde(rename = "minutesAgo", default)]
+    pub minutes_ago: u32,
+    #[serde(rename = "playerName")]
+    pub player_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ChatMessageList {
+    #[serde(rename = "chatMessage", default)]
+    pub chat_message: Vec<ChatMessage>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ChatMessage {
+    pub username: String,
+    /// Milliseconds since the epoch
+    pub time:     u64,
+    pub message:  String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct MusicFolderList {
+    #[serde(rename = "musicFolder", default)]
+    pub music_folder: Vec<MusicFolder>,
+}
+
+/// A library on the server (`getMusicFolders`).
+#[derive(Debug, Deserialize, Clone)]
+pub struct MusicFolder {
+    /// Numeric in the Subsonic spec, a string on some servers.
+    #[serde(deserialize_with = "id_string")]
+    pub id:   String,
+    #[serde(default)]
+    pub name: String,
+}
+
+fn id_string<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => Ok(s),
+        serde_json::Value::Number(n) => Ok(n.to_string()),
+        other => Err(serde::de::Error::custom(format!("invalid id: {}", other))),
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ShareList {
+    #[serde(default)]
+    pub share: Vec<Share>,
+}
+
+/// Public link to songs, an album or a playlist (`createShare`).
+#[derive(Debug, Deserialize, Clone)]
+pub struct Share {
+    pub id:          String,
+    pub url:         String,
+    pub description: Option<String>,
+    pub created:     Option<String>,
+    pub expires:     Option<String>,
+    #[serde(rename = "visitCount", default)]
+    pub visit_count: u32,
+    #[serde(default)]
+    pub entry:       Vec<Song>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct InternetRadioStationList {
+    #[serde(rename = "internetRadioStation", default)]
+    pub internet_radio_station: Vec<InternetRadioStation>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct InternetRadioStation {
+    pub id:            String,
+    pub name:          String,
+    #[serde(rename = "streamUrl")]
+    pub stream_url:    String,
+    #[serde(rename = "homePageUrl")]
+    pub home_page_url: Option<String>,
+}
+
+/// `getArtistInfo2` – biography and similar artists (usually from Last.fm).
+#[derive(Debug, Deserialize, Default)]
+pub struct ArtistInfo {
+    /// May contain HTML, e.g. a "Read more on Last.fm" link.
+    #[serde(default)]
+    pub biography:      Option<String>,
+    #[serde(rename = "similarArtist", default)]
+    pub similar_artist: Vec<Artist>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -52,12 +293,90 @@ pub struct ArtistDetail {
 pub struct Album {
     pub id:        String,
     pub name:      String,
+    #[serde(default)]
     pub artist:    String,
+    #[serde(rename = "artistId")]
+    pub artist_id: Option<String>,
     #[serde(rename = "coverArt")]
     pub cover_art: Option<String>,
     pub year:      Option<i32>,
-    #[serde(rename = "songCount")]
+    pub genre:     Option<String>,
+    #[serde(rename = "songCount", default)]
     pub song_count: u32,
+    #[serde(default)]
+    pub duration:   u64,
+    #[serde(rename = "playCount")]
+    pub play_count: Option<u64>,
+    pub created:    Option<String>,
+    pub starred:    Option<String>,
+    #[serde(rename = "userRating")]
+    pub user_rating: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlbumList2 {
+    #[serde(default)]
+    pub album: Vec<Album>,
+}
+
+/// Sort/filter modes of `getAlbumList2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlbumListType {
+    Newest,
+    Recent,
+    Frequent,
+    Highest,
+    Random,
+    AlphabeticalByName,
+    AlphabeticalByArtist,
+    ByYear { from: i32, to: i32 },
+    ByGenre(String),
+}
+
+impl AlbumListType {
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            AlbumListType::Newest               => "newest",
+            AlbumListType::Recent               => "recent",
+            AlbumListType::Frequent             => "frequent",
+            AlbumListType::Highest              => "highest",
+            AlbumListType::Random               => "random",
+            AlbumListType::AlphabeticalByName   => "alphabeticalByName",
+            AlbumListType::AlphabeticalByArtist => "alphabeticalByArtist",
+            AlbumListType::ByYear { .. }        => "byYear",
+            AlbumListType::ByGenre(_)           => "byGenre",
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            AlbumListType::Newest               => "Recently added".to_string(),
+            AlbumListType::Recent               => "Recently played".to_string(),
+            AlbumListType::Frequent             => "Most played".to_string(),
+            AlbumListType::Highest              => "Highest rated".to_string(),
+            AlbumListType::Random               => "Random".to_string(),
+            AlbumListType::AlphabeticalByName   => "A–Z by name".to_string(),
+            AlbumListType::AlphabeticalByArtist => "A–Z by artist".to_string(),
+            AlbumListType::ByYear { from, to }  => format!("Years {}–{}", from, to),
+            AlbumListType::ByGenre(genre)       => format!("Genre: {}", genre),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GenreList {
+    #[serde(default)]
+    pub genre: Vec<Genre>,
+}
+
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub struct Genre {
+    /// The genre name
+    pub value:       String,
+    #[serde(rename = "songCount", default)]
+    pub song_count:  u32,
+    #[serde(rename = "albumCount", default)]
+    pub album_count: u32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -65,27 +384,166 @@ pub struct AlbumDetail {
     pub song: Vec<Song>,
 }
 
-#[derive(Debug, Deserialize, Clone, Serialize)]
+#[derive(Debug, Deserialize, Clone, Serialize, Default)]
 pub struct Song {
     pub id:       String,
     pub title:    String,
+    #[serde(default)]
     pub duration: u64,
     pub track:    Option<u32>,
     pub artist:   Option<String>,
     pub album:    Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub starred: Option<String>,
+    #[serde(rename = "albumId")]
+    pub album_id:     Option<String>,
+    #[serde(rename = "artistId")]
+    pub artist_id:    Option<String>,
+    #[serde(rename = "coverArt")]
+    pub cover_art:    Option<String>,
+    pub year:         Option<i32>,
+    pub genre:        Option<String>,
+    #[serde(rename = "discNumber")]
+    pub disc_number:  Option<u32>,
+    /// kbit/s
+    #[serde(rename = "bitRate")]
+    pub bit_rate:     Option<u32>,
+    pub suffix:       Option<String>,
+    #[serde(rename = "contentType")]
+    pub content_type: Option<String>,
+    /// Bytes
+    pub size:         Option<u64>,
+    #[serde(rename = "playCount")]
+    pub play_count:   Option<u64>,
+    #[serde(rename = "userRating")]
+    pub user_rating:  Option<u8>,
+    #[serde(rename = "replayGain")]
+    pub replay_gain:  Option<ReplayGain>,
+    /// File path relative to the music folder, e.g. `Abba/Arrival/01 Dancing Queen.flac`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path:         Option<String>,
+    /// Folder entry in `getMusicDirectory` rather than a track.
+    #[serde(rename = "isDir", default)]
+    pub is_dir:       bool,
+}
+
+/// OpenSubsonic ReplayGain values (dB / linear peak).
+#[derive(Debug, Deserialize, Clone, Serialize, Default)]
+pub struct ReplayGain {
+    #[serde(rename = "trackGain")]
+    pub track_gain: Option<f32>,
+    #[serde(rename = "albumGain")]
+    pub album_gain: Option<f32>,
+    #[serde(rename = "trackPeak")]
+    pub track_peak: Option<f32>,
+    #[serde(rename = "albumPeak")]
+    pub album_peak: Option<f32>,
+    #[serde(rename = "baseGain")]
+    pub base_gain:  Option<f32>,
 }
 
 #[derive(Debug, Deserialize)]
-#[allow(dead_code)]
 pub struct MusicDirectory {
+    /// Sub-folders (`is_dir`) and tracks, in the server's order.
+    #[serde(default)]
     pub child: Vec<Song>,
 }
 
-#[derive(Debug, Deserialize)]
+/// `getIndexes` – top-level folders grouped by letter, plus loose tracks in the root.
+#[derive(Debug, Deserialize, Default)]
+pub struct Indexes {
+    #[serde(default)]
+    pub index: Vec<ArtistGroup>,
+    #[serde(default)]
+    pub child: Vec<Song>,
+}
+
+#[derive(Debug, Deserialize, Default)]
 pub struct SearchResult {
-    pub _song: Vec<Song>,  // Unterstrich unterdrückt die Warnung
+    #[serde(default)]
+    pub artist: Vec<Artist>,
+    #[serde(default)]
+    pub album:  Vec<Album>,
+    #[serde(default)]
+    pub song:   Vec<Song>,
+}
+
+/// `getLyrics` – plain text, looked up by artist and title.
+#[derive(Debug, Deserialize)]
+pub struct LyricsText {
+    #[serde(default)]
+    pub value: String,
+}
+
+/// `getLyricsBySongId` (OpenSubsonic `songLyrics`).
+#[derive(Debug, Deserialize)]
+pub struct LyricsList {
+    #[serde(rename = "structuredLyrics", default)]
+    pub structured_lyrics: Vec<StructuredLyrics>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct StructuredLyrics {
+    #[serde(default)]
+    pub synced: bool,
+    /// Milliseconds; positive shows the lines earlier.
+    #[serde(default)]
+    pub offset: i64,
+    #[serde(default)]
+    pub line:   Vec<LyricsLine>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LyricsLine {
+    /// Milliseconds, only for synced lyrics.
+    pub start: Option<u64>,
+    #[serde(default)]
+    pub value: String,
+}
+
+/// Queue saved with `savePlayQueue`, possibly by another client.
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayQueue {
+    #[serde(default)]
+    pub entry:      Vec<Song>,
+    /// Id of the song that was playing.
+    pub current:    Option<String>,
+    /// Position within `current` in milliseconds.
+    #[serde(default)]
+    pub position:   u64,
+    #[serde(rename = "changedBy")]
+    pub changed_by: Option<String>,
+}
+
+/// What `star` / `unstar` act on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StarTarget {
+    Song,
+    Album,
+    Artist,
+}
+
+impl StarTarget {
+    /// Request parameter carrying the id.
+    pub fn param(&self) -> &'static str {
+        match self {
+            StarTarget::Song   => "id",
+            StarTarget::Album  => "albumId",
+            StarTarget::Artist => "artistId",
+        }
+    }
+}
+
+/// Result counts and offsets per entity type for one `search3` request.
+/// A count of 0 leaves that type out.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SearchPage {
+    pub artist_count:  u32,
+    pub artist_offset: u32,
+    pub album_count:   u32,
+    pub album_offset:  u32,
+    pub song_count:    u32,
+    pub song_offset:   u32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -94,6 +552,13 @@ pub struct RandomSongList {
     pub song: Vec<Song>,
 }
 
+/// Plain `{ "song": [...] }` payloads (`songsByGenre`, …).
+#[derive(Debug, Deserialize)]
+pub struct SongList {
+    #[serde(default)]
+    pub song: Vec<Song>,
+}
+
 #[derive(Debug, Deserialize, Clone, Serialize)]
 pub struct Playlist {
     pub id:         String,
@@ -105,6 +570,19 @@ pub struct Playlist {
     #[serde(rename = "coverArt")]
     pub cover_art:  Option<String>,
     pub comment:    Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public:     Option<bool>,
+}
+
+/// Changes for one `updatePlaylist` call; `None` / empty fields stay untouched.
+#[derive(Debug, Clone, Default)]
+pub struct PlaylistUpdate {
+    pub name:                   Option<String>,
+    pub comment:                Option<String>,
+    pub public:                 Option<bool>,
+    pub song_ids_to_add:        Vec<String>,
+    /// Positions in the playlist *before* the update.
+    pub song_indexes_to_remove: Vec<usize>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -117,3 +595,35 @@ pub struct PlaylistSongs {
     #[serde(default)]
     pub entry: Vec<Song>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn song_carries_full_metadata() {
+        let song: Song = serde_json::from_value(serde_json::json!({
+            "id": "s-1", "title": "Dancing Queen", "duration": 231, "track": 2,
+            "artist": "ABBA", "album": "Arrival", "albumId": "al-1", "artistId": "ar-1",
+            "coverArt": "mf-s-1", "year": 1976, "genre": "Pop", "discNumber": 1,
+            "bitRate": 1411, "suffix": "flac", "contentType": "audio/flac", "size": 40_000_000,
+            "playCount": 12, "userRating": 5,
+            "replayGain": {"trackGain": -7.5, "albumGain": -8.1, "trackPeak": 0.98},
+        })).unwrap();
+        assert_eq!(song.cover_art.as_deref(), Some("mf-s-1"));
+        assert_eq!(song.album_id.as_deref(), Some("al-1"));
+        assert_eq!(song.bit_rate, Some(1411));
+        assert_eq!(song.user_rating, Some(5));
+        assert_eq!(song.replay_gain.and_then(|g| g.track_gain), Some(-7.5));
+    }
+
+    #[test]
+    fn minimal_entries_still_parse() {
+        // Radio-like entries and old state files lack most fields
+        let song: Song = serde_json::from_value(serde_json::json!({"id": "x", "title": "t"})).unwrap();
+        assert_eq!(song.duration, 0);
+        let album: Album = serde_json::from_value(serde_json::json!({"id": "a", "name": "n"})).unwrap();
+        assert_eq!(album.song_count, 0);
+        assert!(album.starred.is_none());
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::Result;
+
+use crate::api::models::AlbumListType;
+use super::{App, InputPrompt, InputPurpose, PanelState, ViewMode};
+
+/// Albums fetched per `getAlbumList2` page.
+const PAGE_SIZE: u32 = 50;
+/// Start fetching the next page when the selection is this close to the end.
+pub(super) const PAGE_AHEAD: usize = 5;
+
+pub enum AlbumListPreset {
+    List(AlbumListType),
+    /// Needs a year range / genre from the input prompt first
+    Ask(InputPurpose),
+}
+
+/// Entries of the album browser's left column.
+pub static ALBUM_LIST_PRESETS: [(&str, AlbumListPreset); 9] = [
+    ("🆕 Recently added",  AlbumListPreset::List(AlbumListType::Newest)),
+    ("🕘 Recently played", AlbumListPreset::List(AlbumListType::Recent)),
+    ("🔥 Most played",     AlbumListPreset::List(AlbumListType::Frequent)),
+    ("⭐ Highest rated",   AlbumListPreset::List(AlbumListType::Highest)),
+    ("🎲 Random",          AlbumListPreset::List(AlbumListType::Random)),
+    ("🔤 A–Z by name",     AlbumListPreset::List(AlbumListType::AlphabeticalByName)),
+    ("🎤 A–Z by artist",   AlbumListPreset::List(AlbumListType::AlphabeticalByArtist)),
+    ("📅 By year…",        AlbumListPreset::Ask(InputPurpose::YearRange)),
+    ("🎸 By genre…",       AlbumListPreset::Ask(InputPurpose::Genre)),
+];
+
+impl App {
+    /// Shift+A – toggles the album browser.
+    pub fn toggle_album_browser(&mut self) {
+        self.close_search();
+        self.current_genre = None;
+        if matches!(self.mode, ViewMode::AlbumList) || self.album_list.is_some() {
+            self.album_list = None;
+            self.albums.clear();
+            self.album_state = PanelState::default();
+            self.mode = ViewMode::Artists;
+        } else {
+            self.current_album = None;
+            self.albums.clear();
+            self.album_state = PanelState::default();
+            self.mode = ViewMode::AlbumList;
+        }
+    }
+
+    /// Enter on a preset: load it, or ask for the year range / genre first.
+    pub async fn select_album_list_preset(&mut self) -> Result<()> {
+        match ALBUM_LIST_PRESETS.get(self.album_list_state.selected) {
+            Some((_, AlbumListPreset::List(list))) => self.open_album_list(list.clone()).await,
+            Some((_, AlbumListPreset::Ask(purpose))) => {
+                self.input = Some(InputPrompt::new(*purpose));
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Loads the first page of `list` into the album column.
+    pub async fn open_album_list(&mut self, list: AlbumListType) -> Result<()> {
+        let albums = self.client.get_album_list(&list, PAGE_SIZE, 0).await?;
+        self.album_list_exhausted = (albums.len() as u32) < PAGE_SIZE;
+        self.status_message = format!("📀 {} – {} albums{}", list.label(), albums.len(),
+            if self.album_list_exhausted { "" } else { "+" });
+        self.albums        = albums;
+        self.album_list    = Some(list);
+        self.current_album = None;
+        self.album_state   = PanelState::default();
+        self.mode          = ViewMode::Albums;
+        Ok(())
+    }
+
+    /// Fetches the next page once the selection gets close to the end of the list.
+    pub async fn load_more_albums(&mut self) -> Result<()> {
+        let Some(list) = self.album_list.clone() else { return Ok(()); };
+        if self.album_list_exhausted || self.mode != ViewMode::Albums { return Ok(()); }
+        if self.album_state.selected + PAGE_AHEAD < self.albums.len() { return Ok(()); }
+
+        let more = self.client.get_album_list(&list, PAGE_SIZE, self.albums.len() as u32).await?;
+        self.album_list_exhausted = (more.len() as u32) < PAGE_SIZE;
+        // Random lists never end and may repeat albums – skip the ones we already have
+        let fresh: Vec<_> = more.into_iter()
+            .filter(|a| !self.albums.iter().any(|have| have.id == a.id))
+            .collect();
+        self.albums.extend(fresh);
+        Ok(())
+    }
+}
+
+/// Parses `1990-1999`, `1990–1999` or a single `1994`.
+pub fn parse_year_range(input: &str) -> Option<(i32, i32)> {
+    let input = input.trim();
+    match input.split_once(['-', '–']) {
+        Some((a, b)) => Some((a.trim().parse().ok()?, b.trim().parse().ok()?)),
+        None         => input.parse().ok().map(|y| (y, y)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn preset_opens_first_page() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_album_browser();
+        assert_eq!(app.mode, ViewMode::AlbumList);
+        app.select_album_list_preset().await.unwrap();
+        assert_eq!(app.mode, ViewMode::Albums);
+        assert_eq!(app.album_list, Some(AlbumListType::Newest));
+        assert_eq!(app.albums.len(), 50);
+        assert_eq!(mock.calls_to("getAlbumList2")[0].param("type"), Some("newest"));
+
+        app.go_back();
+        assert_eq!(app.mode, ViewMode::AlbumList);
+    }
+
+    #[tokio::test]
+    async fn album_list_pages_near_the_end() {
+        let (mut app, mock) = app_with_mock().await;
+        app.open_album_list(AlbumListType::Newest).await.unwrap();
+
+        // Far from the end: no request
+        app.load_more_albums().await.unwrap();
+        assert_eq!(mock.calls_to("getAlbumList2").len(), 1);
+
+        for _ in 0..3 {
+            app.album_state.selected = app.albums.len() - 1;
+            app.load_more_albums().await.unwrap();
+        }
+        assert_eq!(app.albums.len(), 120);
+        assert!(app.album_list_exhausted);
+        let calls = mock.calls_to("getAlbumList2");
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls[2].param("offset"), Some("100"));
+    }
+
+    #[tokio::test]
+    async fn failed_page_keeps_loaded_albums() {
+        let (mut app, mock) = app_with_mock().await;
+        app.open_album_list(AlbumListType::Newest).await.unwrap();
+        mock.fail_with("getAlbumList2", 0, "Database busy");
+        app.album_state.selected = app.albums.len() - 1;
+        assert!(app.load_more_albums().await.is_err());
+        assert_eq!(app.albums.len(), 50);
+        assert!(!app.album_list_exhausted);
+    }
+
+    #[tokio::test]
+    async fn failed_preset_stays_in_the_browser() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getAlbumList2", 0, "Database busy");
+        app.toggle_album_browser();
+        assert!(app.select_album_list_preset().await.is_err());
+        assert_eq!(app.mode, ViewMode::AlbumList);
+        assert!(app.album_list.is_none());
+    }
+
+    #[tokio::test]
+    async fn year_preset_asks_for_a_range() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_album_browser();
+        app.album_list_state.selected = 7;
+        app.select_album_list_preset().await.unwrap();
+        let mut prompt = app.input.clone().expect("year prompt");
+        prompt.text = "1970 - 1979".to_string();
+        app.input = Some(prompt);
+        app.submit_input().await.unwrap();
+        assert_eq!(app.album_list, Some(AlbumListType::ByYear { from: 1970, to: 1979 }));
+        let call = &mock.calls_to("getAlbumList2")[0];
+        assert_eq!(call.param("fromYear"), Some("1970"));
+        assert_eq!(call.param("toYear"), Some("1979"));
+    }
+
+    #[test]
+    fn year_ranges_parse() {
+        assert_eq!(parse_year_range("1990-1999"), Some((1990, 1999)));
+        assert_eq!(parse_year_range("1990–1999"), Some((1990, 1999)));
+        assert_eq!(parse_year_range(" 1994 "), Some((1994, 1994)));
+        assert_eq!(parse_year_range("nineties"), None);
+    }
+}
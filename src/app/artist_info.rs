@@ -0,0 +1,176 @@
+use anyhow::Result;
+
+use crate::api::models::{Artist, Song};
+use super::{App, PanelState, ViewMode};
+
+/// Similar artists asked for per `getArtistInfo2` call.
+const SIMILAR_COUNT: u32 = 10;
+/// Songs asked for per `getTopSongs` call.
+const TOP_SONGS_COUNT: u32 = 10;
+
+/// What the artist info panel shows. Its list is the similar artists followed
+/// by the top songs, sharing one selection.
+pub struct ArtistInfoView {
+    pub artist:    Artist,
+    pub biography: String,
+    pub similar:   Vec<Artist>,
+    pub top_songs: Vec<Song>,
+    /// Where ← / Shift+I return to.
+    return_to:     ViewMode,
+}
+
+impl ArtistInfoView {
+    /// Entries of the similar-artists + top-songs list.
+    pub fn entries(&self) -> usize { self.similar.len() + self.top_songs.len() }
+
+    /// `getCoverArt` id of the artist image.
+    pub fn cover_id(&self) -> &str {
+        self.artist.cover_art.as_deref().unwrap_or(&self.artist.id)
+    }
+}
+
+impl App {
+    /// Shift+I – biography, similar artists and top songs of the selected
+    /// artist (the one of the open album or the playing song elsewhere).
+    pub async fn toggle_artist_info(&mut self) -> Result<()> {
+        if self.mode == ViewMode::ArtistInfo {
+            self.close_artist_info();
+            return Ok(());
+        }
+        let artist = match self.mode {
+            ViewMode::Artists => self.artists.get(self.artist_state.selected).cloned(),
+            ViewMode::Albums | ViewMode::Songs if self.current_artist.is_some() => self.current_artist.clone(),
+            _ => self.selected_song().and_then(|s| Some(Artist {
+                id:        s.artist_id.clone()?,
+                name:      s.artist.clone()?,
+                starred:   None,
+                cover_art: None,
+            })),
+        };
+        let Some(artist) = artist else {
+            self.status_message = "No artist selected".to_string();
+            return Ok(());
+        };
+        let return_to = self.mode;
+        self.open_artist_info(artist, return_to).await
+    }
+
+    async fn open_artist_info(&mut self, artist: Artist, return_to: ViewMode) -> Result<()> {
+        let info      = self.client.get_artist_info(&artist.id, SIMILAR_COUNT).await?;
+        let top_songs = self.client.get_top_songs(&artist.name, TOP_SONGS_COUNT).await?;
+        self.artist_info = Some(ArtistInfoView {
+            biography: info.biography.as_deref().map(strip_html).unwrap_or_default(),
+            similar:   info.similar_artist,
+            top_songs,
+            artist,
+            return_to,
+        });
+        self.artist_info_state = PanelState::default();
+        self.mode              = ViewMode::ArtistInfo;
+        Ok(())
+    }
+
+    /// Enter: a similar artist opens their info, a top song plays the top songs from there.
+    pub async fn activate_artist_info_entry(&mut self) -> Result<()> {
+        let Some(view) = self.artist_info.as_ref() else { return Ok(()); };
+        let selected   = self.artist_info_state.selected;
+        if let Some(similar) = view.similar.get(selected).cloned() {
+            let return_to = view.return_to;
+            // Keep the artist column in step so ← lands on the new artist
+            if let Some(pos) = self.artists.iter().position(|a| a.id == similar.id) {
+                self.artist_state.selected = pos;
+            }
+            return self.open_artist_info(similar, return_to).await;
+        }
+        if selected < view.entries() {
+            self.songs      = view.top_songs.clone();
+            self.song_state = PanelState { selected: selected - view.similar.len(), scroll: 0 };
+            self.is_shuffle = false;
+            let name = view.artist.name.clone();
+            self.start_playback().await?;
+            self.status_message = format!("🔝 Top songs of {}", name);
+        }
+        Ok(())
+    }
+
+    /// ← – back to where the panel was opened from.
+    pub fn close_artist_info(&mut self) {
+        self.mode = self.artist_info.take().map_or(ViewMode::Artists, |view| view.return_to);
+    }
+}
+
+/// Drops HTML tags (Last.fm biographies end in a "Read more" link) and
+/// decodes the few entities that show up in them.
+fn strip_html(html: &str) -> String {
+    let mut text   = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&amp;", "&").replace("&quot;", "\"").replace("&#39;", "'")
+        .replace("&lt;", "<").replace("&gt;", ">")
+        .trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn info_shows_biography_similar_artists_and_top_songs() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_artist_info().await.unwrap();
+        assert_eq!(app.mode, ViewMode::ArtistInfo);
+        let view = app.artist_info.as_ref().unwrap();
+        assert_eq!(view.biography, "Swedish pop group. Read more on Last.fm");
+        assert_eq!(view.similar[0].name, "Beatles");
+        assert_eq!(mock.calls_to("getTopSongs")[0].param("artist"), Some("Abba"));
+
+        app.go_back();
+        assert_eq!(app.mode, ViewMode::Artists);
+        assert!(app.artist_info.is_none());
+    }
+
+    #[tokio::test]
+    async fn similar_artist_opens_their_info() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_artist_info().await.unwrap();
+        app.activate_artist_info_entry().await.unwrap();
+        assert_eq!(app.artist_info.as_ref().unwrap().artist.id, "ar-2");
+        assert_eq!(app.artist_state.selected, 1);
+        assert!(app.artist_info.as_ref().unwrap().biography.is_empty());
+    }
+
+    #[tokio::test]
+    async fn top_song_plays_the_top_songs_from_there() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_artist_info().await.unwrap();
+        // Past the one similar artist to the second top song
+        app.on_down();
+        app.on_down();
+        app.activate_artist_info_entry().await.unwrap();
+        assert_eq!(app.songs.len(), 2);
+        assert_eq!(app.now_playing, Some(1));
+        assert!(app.status_message.contains("Top songs of Abba"), "{}", app.status_message);
+    }
+
+    #[tokio::test]
+    async fn failed_info_stays_in_the_artist_list() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getArtistInfo2", 70, "Artist not found");
+        assert!(app.toggle_artist_info().await.is_err());
+        assert_eq!(app.mode, ViewMode::Artists);
+        assert!(app.artist_info.is_none());
+    }
+
+    #[test]
+    fn html_is_stripped_from_biographies() {
+        assert_eq!(strip_html("Tom &amp; Jerry <a href=\"x\">more</a> "), "Tom & Jerry more");
+    }
+}
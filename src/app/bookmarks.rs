@@ -0,0 +1,242 @@
+use anyhow::Result;
+use std::sync::atomic::Ordering;
+
+use crate::api::models::Song;
+use super::{App, PanelState, ViewMode};
+
+/// Positions this close to the start or end aren't worth a bookmark.
+const EDGE_MARGIN_SECS: u64 = 30;
+
+/// Playing track whose position is bookmarked when it's stopped or skipped.
+pub struct BookmarkCandidate {
+    /// Song or episode stream id – what bookmarks are keyed by.
+    pub id:         String,
+    pub duration:   u64,
+    /// A bookmark exists on the server (we resumed from or created one).
+    pub bookmarked: bool,
+}
+
+impl App {
+    /// Long enough for `[bookmarks] min_minutes`?
+    pub(super) fn bookmark_candidate_for(&self, song: &Song) -> Option<BookmarkCandidate> {
+        let min_minutes = self.client.config().bookmarks.min_minutes;
+        (min_minutes > 0 && song.duration >= min_minutes * 60).then(|| BookmarkCandidate {
+            id:         song.id.clone(),
+            duration:   song.duration,
+            bookmarked: false,
+        })
+    }
+
+    /// Saves where the playing track stopped. Called before playback stops,
+    /// skips or is replaced, while `current_time` still belongs to it.
+    pub(super) async fn bookmark_playing_track(&mut self) {
+        let Some(track) = self.bookmark_candidate.take() else { return; };
+        let position_secs = self.player_status.current_time.load(Ordering::Relaxed) as u64 / 1000;
+        if position_secs < EDGE_MARGIN_SECS { return; }
+        let finished = track.duration > 0 && position_secs + EDGE_MARGIN_SECS >= track.duration;
+        let result = if finished {
+            // Practically done – don't resume near the end next time
+            if !track.bookmarked { return; }
+            self.client.delete_bookmark(&track.id).await
+        } else {
+            self.client.create_bookmark(&track.id, position_secs * 1000).await
+        };
+        if let Err(e) = result {
+            self.status_message = format!("❌ Failed to update bookmark: {}", e);
+        }
+    }
+
+    /// The bookmarked track played to its end – its bookmark is obsolete.
+    pub(super) async fn finish_bookmarked_track(&mut self) {
+        let Some(track) = self.bookmark_candidate.take() else { return; };
+        if track.bookmarked {
+            if let Err(e) = self.client.delete_bookmark(&track.id).await {
+                self.status_message = format!("❌ Failed to delete bookmark: {}", e);
+            }
+        }
+    }
+
+    /// Shift+B – saved positions, newest first.
+    pub async fn toggle_bookmarks(&mut self) -> Result<()> {
+        if self.mode == ViewMode::Bookmarks {
+            self.mode = ViewMode::Artists;
+            return Ok(());
+        }
+        self.close_search();
+        self.refresh_bookmarks().await?;
+        self.bookmark_state = PanelState::default();
+        self.mode           = ViewMode::Bookmarks;
+        Ok(())
+    }
+
+    async fn refresh_bookmarks(&mut self) -> Result<()> {
+        let mut bookmarks = self.client.get_bookmarks().await?;
+        bookmarks.sort_by(|a, b| b.changed.cmp(&a.changed));
+        self.bookmarks = bookmarks;
+        self.bookmark_state.selected = self.bookmark_state.selected.min(self.bookmarks.len().saturating_sub(1));
+        Ok(())
+    }
+
+    /// Enter on a bookmark: play the track from the saved position (`--start`).
+    pub async fn resume_bookmark(&mut self) -> Result<()> {
+        let Some(bookmark) = self.bookmarks.get(self.bookmark_state.selected).cloned() else {
+            return Ok(());
+        };
+        let song = bookmark.entry;
+        self.resume_at  = Some(bookmark.position / 1000);
+        self.songs      = vec![song.clone()];
+        self.song_state = PanelState::default();
+        self.is_shuffle = false;
+        self.start_playback().await?;
+        self.bookmark_candidate = Some(BookmarkCandidate { id: song.id, duration: song.duration, bookmarked: true });
+        self.status_message = format!("🔖 {} – resuming at {}", song.title, format_position(bookmark.position));
+        Ok(())
+    }
+
+    /// Delete on a bookmark – press twice.
+    pub async fn delete_selected_bookmark(&mut self) -> Result<()> {
+        let Some(bookmark) = self.bookmarks.get(self.bookmark_state.selected) else { return Ok(()); };
+        let (id, title) = (bookmark.entry.id.clone(), bookmark.entry.title.clone());
+        if self.pending_delete.as_deref() != Some(id.as_str()) {
+            self.pending_delete = Some(id);
+            self.status_message = format!("Press Delete again to delete the bookmark in '{}'", title);
+            return Ok(());
+        }
+        self.pending_delete = None;
+        self.client.delete_bookmark(&id).await?;
+        self.refresh_bookmarks().await?;
+        self.status_message = format!("🗑 Bookmark deleted: {}", title);
+        Ok(())
+    }
+}
+
+/// Milliseconds → `h:mm:ss` / `m:ss`.
+pub fn format_position(ms: u64) -> String {
+    let secs = ms / 1000;
+    match secs / 3600 {
+        0 => format!("{}:{:02}", secs / 60, secs % 60),
+        h => format!("{}:{:02}:{:02}", h, secs / 60 % 60, secs % 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+    use crate::api::mock::MockServer;
+
+    /// Bookmark view with the newest bookmark resumed.
+    async fn resumed_bookmark() -> (App, MockServer) {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_bookmarks().await.unwrap();
+        app.resume_bookmark().await.unwrap();
+        (app, mock)
+    }
+
+    #[tokio::test]
+    async fn bookmarks_are_listed_newest_first_and_resumed() {
+        let (app, _mock) = resumed_bookmark().await;
+        assert_eq!(app.mode, ViewMode::Bookmarks);
+        assert_eq!(app.bookmarks[0].entry.id, "ab-1");
+        assert_eq!(app.songs[0].id, "ab-1");
+        assert!(app.status_message.contains("resuming at 1:30:00"), "{}", app.status_message);
+    }
+
+    #[tokio::test]
+    async fn stopping_updates_the_bookmark() {
+        let (mut app, mock) = resumed_bookmark().await;
+        app.player_status.current_time.store(6_000_000, Ordering::Relaxed);
+        app.stop_playback().await;
+        assert_eq!(mock.calls_to("createBookmark")[0].param("position"), Some("6000000"));
+    }
+
+    #[tokio::test]
+    async fn failed_bookmark_update_is_reported() {
+        let (mut app, mock) = resumed_bookmark().await;
+        mock.fail_with("createBookmark", 50, "Not allowed");
+        app.player_status.current_time.store(6_000_000, Ordering::Relaxed);
+        app.stop_playback().await;
+        assert!(app.status_message.contains("Not allowed"), "{}", app.status_message);
+    }
+
+    #[tokio::test]
+    async fn stopping_near_the_end_deletes_the_bookmark() {
+        let (mut app, mock) = resumed_bookmark().await;
+        app.player_status.current_time.store(39_990_000, Ordering::Relaxed);
+        app.stop_playback().await;
+        assert_eq!(mock.calls_to("deleteBookmark")[0].param("id"), Some("ab-1"));
+        assert!(mock.calls_to("createBookmark").is_empty());
+    }
+
+    #[tokio::test]
+    async fn resumed_track_keeps_its_bookmark_when_mpv_reports_it() {
+        let (mut app, mock) = resumed_bookmark().await;
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        assert!(mock.calls_to("deleteBookmark").is_empty());
+        assert!(app.bookmark_candidate.as_ref().is_some_and(|c| c.id == "ab-1" && c.bookmarked));
+
+        // Still known as bookmarked: stopping at the end removes it
+        app.player_status.current_time.store(39_990_000, Ordering::Relaxed);
+        app.stop_playback().await;
+        assert_eq!(mock.calls_to("deleteBookmark")[0].param("id"), Some("ab-1"));
+    }
+
+    #[tokio::test]
+    async fn bookmark_of_a_track_played_to_its_end_is_deleted() {
+        let (mut app, mock) = resumed_bookmark().await;
+        app.songs.push(Song { id: "next".into(), duration: 200, ..Default::default() });
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        app.player_status.current_index.store(1, Ordering::Release);
+        app.update_now_playing().await;
+        assert_eq!(mock.calls_to("deleteBookmark")[0].param("id"), Some("ab-1"));
+        assert!(app.bookmark_candidate.is_none());
+    }
+
+    #[tokio::test]
+    async fn deleting_a_bookmark_needs_confirmation() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_bookmarks().await.unwrap();
+        app.delete_selected_bookmark().await.unwrap();
+        assert!(mock.calls_to("deleteBookmark").is_empty());
+        app.delete_selected_bookmark().await.unwrap();
+        assert_eq!(mock.calls_to("deleteBookmark")[0].param("id"), Some("ab-1"));
+    }
+
+    #[tokio::test]
+    async fn failed_bookmark_list_stays_in_the_library() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getBookmarks", 0, "Database busy");
+        assert!(app.toggle_bookmarks().await.is_err());
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+
+    #[tokio::test]
+    async fn long_tracks_are_bookmarked_when_skipped() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = vec![
+            Song { id: "short".into(), duration: 300,  ..Default::default() },
+            Song { id: "long".into(),  duration: 3000, ..Default::default() },
+        ];
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        app.player_status.current_time.store(120_000, Ordering::Relaxed);
+        app.next_track().await;
+        assert!(mock.calls_to("createBookmark").is_empty());
+
+        app.player_status.current_index.store(1, Ordering::Release);
+        app.update_now_playing().await;
+        app.player_status.current_time.store(600_000, Ordering::Relaxed);
+        app.next_track().await;
+        let call = &mock.calls_to("createBookmark")[0];
+        assert_eq!(call.param("id"), Some("long"));
+        assert_eq!(call.param("position"), Some("600000"));
+    }
+
+    #[test]
+    fn positions_format_with_hours_only_when_needed() {
+        assert_eq!(format_position(95_000), "1:35");
+        assert_eq!(format_position(5_400_000), "1:30:00");
+    }
+}
@@ -0,0 +1,158 @@
+use anyhow::Result;
+use std::time::{Duration, Instant};
+
+use crate::api::models::ChatMessage;
+use super::{take_finished, App, InputPrompt, InputPurpose};
+
+/// How often the open chat asks for new messages.
+const CHAT_POLL: Duration = Duration::from_secs(5);
+/// Messages kept in memory; older ones scroll out.
+const CHAT_HISTORY: usize = 200;
+
+impl App {
+    /// Shift+C – the server's chat in the right column.
+    pub fn toggle_chat(&mut self) {
+        self.show_chat = !self.show_chat;
+        if self.show_chat {
+            self.poll_chat();
+        }
+    }
+
+    /// Called every UI tick; picks up finished polls and starts the next
+    /// one while the chat is shown.
+    pub async fn chat_tick(&mut self) {
+        if let Some(result) = take_finished(&mut self.chat_poll).await {
+            match result {
+                Ok(messages) => self.add_chat_messages(messages),
+                Err(e)       => self.status_message = format!("❌ Chat: {}", e),
+            }
+        }
+        if !self.show_chat { return; }
+        if self.chat_polled.is_some_and(|t| t.elapsed() < CHAT_POLL) { return; }
+        self.poll_chat();
+    }
+
+    /// Fetches messages newer than the last one seen in the background. While
+    /// a poll is running, the next tick after it polls again.
+    fn poll_chat(&mut self) {
+        if self.chat_poll.is_some() {
+            self.chat_polled = None;
+            return;
+        }
+        self.chat_polled = Some(Instant::now());
+        let client = self.client.clone();
+        let since  = self.chat_since;
+        self.chat_poll = Some(tokio::spawn(async move { client.get_chat_messages(since).await }));
+    }
+
+    fn add_chat_messages(&mut self, messages: Vec<ChatMessage>) {
+        let since = self.chat_since.unwrap_or(0);
+        self.chat_messages.extend(messages.into_iter().filter(|m| m.time > since));
+        self.chat_since = self.chat_messages.last().map(|m| m.time).or(self.chat_since);
+        let overflow = self.chat_messages.len().saturating_sub(CHAT_HISTORY);
+        self.chat_messages.drain(..overflow);
+    }
+
+    /// Shift+K – write a message (opens the chat if needed).
+    pub fn start_chat_message(&mut self) {
+        if !self.show_chat {
+            self.toggle_chat();
+        }
+        self.input = Some(InputPrompt::new(InputPurpose::ChatMessage));
+    }
+
+    pub async fn post_chat_message(&mut self, message: &str) -> Result<()> {
+        self.client.add_chat_message(message).await?;
+        self.poll_chat();
+        Ok(())
+    }
+
+    /// Shift+Z – tell the chat what's playing.
+    pub async fn post_playing_song_to_chat(&mut self) -> Result<()> {
+        let Some(song) = self.now_playing.and_then(|i| self.songs.get(i)).cloned() else {
+            self.status_message = "💬 Nothing playing to share".to_string();
+            return Ok(());
+        };
+        let message = match &song.artist {
+            Some(artist) => format!("🎵 Now playing: {} – {}", song.title, artist),
+            None         => format!("🎵 Now playing: {}", song.title),
+        };
+        if !self.show_chat {
+            self.toggle_chat();
+        }
+        self.post_chat_message(&message).await?;
+        self.status_message = format!("💬 Posted {} to the chat", song.title);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    /// Ticks until the background poll has been picked up.
+    async fn finish_poll(app: &mut App) {
+        while app.chat_poll.is_some() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            app.chat_tick().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn chat_polls_since_the_last_message() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_chat();
+        finish_poll(&mut app).await;
+        let users: Vec<_> = app.chat_messages.iter().map(|m| m.username.as_str()).collect();
+        assert_eq!(users, ["anna", "ben"]); // oldest first
+        assert_eq!(mock.calls_to("getChatMessages")[0].param("since"), None);
+
+        app.chat_polled = None;
+        app.chat_tick().await;
+        finish_poll(&mut app).await;
+        assert_eq!(mock.calls_to("getChatMessages")[1].param("since"), Some("1790000200000"));
+        assert_eq!(app.chat_messages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn typed_message_is_posted() {
+        let (mut app, mock) = app_with_mock().await;
+        app.start_chat_message();
+        assert!(app.show_chat);
+        app.input.as_mut().unwrap().text = "Bring it on".to_string();
+        app.submit_input().await.unwrap();
+        assert_eq!(mock.calls_to("addChatMessage")[0].param("message"), Some("Bring it on"));
+    }
+
+    #[tokio::test]
+    async fn playing_song_is_posted() {
+        let (mut app, mock) = app_with_mock().await;
+        app.post_playing_song_to_chat().await.unwrap();
+        assert!(mock.calls_to("addChatMessage").is_empty()); // nothing playing
+        app.songs       = app.client.get_album_songs("1").await.unwrap();
+        app.now_playing = Some(0);
+        app.post_playing_song_to_chat().await.unwrap();
+        assert_eq!(mock.calls_to("addChatMessage")[0].param("message"), Some("🎵 Now playing: Dancing Queen – Mock Artist"));
+    }
+
+    #[tokio::test]
+    async fn failed_post_is_an_error() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("addChatMessage", 50, "Chat is disabled");
+        let err = app.post_chat_message("Hello").await.unwrap_err();
+        assert!(err.to_string().contains("Chat is disabled"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn failed_poll_is_reported() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getChatMessages", 50, "Chat is disabled");
+        app.toggle_chat();
+        finish_poll(&mut app).await;
+        assert!(app.status_message.contains("Chat is disabled"), "{}", app.status_message);
+        assert!(app.chat_messages.is_empty());
+    }
+}
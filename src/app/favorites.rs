@@ -0,0 +1,167 @@
+use anyhow::Result;
+
+use crate::api::models::StarTarget;
+use super::{search::ResultSource, App, ViewMode};
+
+impl App {
+    /// Shift+F – starred artists, albums and songs in the three panels.
+    /// Pressing it again (or Esc) returns to the library.
+    pub async fn toggle_favorites(&mut self) -> Result<()> {
+        if let Some(search) = &self.search {
+            if matches!(search.source, ResultSource::Favorites) {
+                self.close_search();
+                return Ok(());
+            }
+        }
+        let starred = self.client.get_starred().await?;
+        self.status_message = format!("❤️ {} artists, {} albums, {} songs",
+            starred.artist.len(), starred.album.len(), starred.song.len());
+        self.show_results(ResultSource::Favorites, starred, (false, false, false));
+        Ok(())
+    }
+
+    /// Shift+L – stars or unstars the selected artist, album or song (in the
+    /// jukebox and visualizer: the playing song).
+    pub async fn toggle_star(&mut self) -> Result<()> {
+        let selected = match self.mode {
+            ViewMode::Artists => self.artists.get(self.artist_state.selected)
+                .map(|a| (StarTarget::Artist, a.id.clone(), a.name.clone(), a.starred.is_some())),
+            ViewMode::Albums => self.albums.get(self.album_state.selected)
+                .map(|a| (StarTarget::Album, a.id.clone(), a.name.clone(), a.starred.is_some())),
+            ViewMode::Songs | ViewMode::PlaylistSongs | ViewMode::Jukebox | ViewMode::Visualizer => self.selected_song()
+                .map(|s| (StarTarget::Song, s.id.clone(), s.title.clone(), s.starred.is_some())),
+            _ => None,
+        };
+        let Some((target, id, name, starred)) = selected else {
+            self.status_message = "❌ Nothing selected to like".to_string();
+            return Ok(());
+        };
+
+        if starred {
+            self.client.unstar(target, &id).await?;
+            self.status_message = format!("🤍 Unliked: {}", name);
+        } else {
+            self.client.star(target, &id).await?;
+            self.status_message = format!("❤️ Liked: {}", name);
+        }
+        self.set_starred(target, &id, !starred);
+        Ok(())
+    }
+
+    /// Updates `starred` on every loaded copy of the item, including the
+    /// library's artists kept aside during a search.
+    fn set_starred(&mut self, target: StarTarget, id: &str, starred: bool) {
+        // The server's value is a timestamp; any `Some` marks the item as starred
+        let value = starred.then(|| "true".to_string());
+        match target {
+            StarTarget::Artist => {
+                let library = self.search.iter_mut().flat_map(|s| s.library_artists.iter_mut());
+                for artist in self.artists.iter_mut().chain(self.current_artist.as_mut()).chain(library)
+                    .filter(|a| a.id == id)
+                {
+                    artist.starred = value.clone();
+                }
+            }
+            StarTarget::Album => {
+                for album in self.albums.iter_mut().chain(self.current_album.as_mut()).filter(|a| a.id == id) {
+                    album.starred = value.clone();
+                }
+            }
+            StarTarget::Song => {
+                for song in self.songs.iter_mut().filter(|s| s.id == id) {
+                    song.starred = value.clone();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn like_in_jukebox_stars_playing_track() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.mode  = ViewMode::Jukebox;
+        app.player_status.current_index.store(1, Ordering::Release);
+        app.toggle_star().await.unwrap();
+        assert!(app.songs[1].starred.is_some());
+        assert_eq!(mock.calls_to("star")[0].param("id"), Some("s-2"));
+    }
+
+    #[tokio::test]
+    async fn like_stars_selected_artist() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_star().await.unwrap();
+        assert_eq!(mock.calls_to("star")[0].param("artistId"), Some("ar-1"));
+        assert!(app.artists[0].starred.is_some());
+    }
+
+    #[tokio::test]
+    async fn like_toggles_selected_album() {
+        let (mut app, mock) = app_with_mock().await;
+        app.load_albums().await.unwrap();
+        app.toggle_star().await.unwrap();
+        app.toggle_star().await.unwrap();
+        assert_eq!(mock.calls_to("star")[0].param("albumId"), Some("1"));
+        assert_eq!(mock.calls_to("unstar")[0].param("albumId"), Some("1"));
+        assert!(app.albums[0].starred.is_none());
+    }
+
+    #[tokio::test]
+    async fn failed_like_leaves_item_unstarred() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("star", 50, "Not allowed");
+        let err = app.toggle_star().await.unwrap_err();
+        assert!(err.to_string().contains("Not allowed"), "{}", err);
+        assert!(app.artists[0].starred.is_none());
+    }
+
+    #[tokio::test]
+    async fn favorites_view_lists_starred_items() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_favorites().await.unwrap();
+        assert_eq!((app.artists.len(), app.albums.len(), app.songs.len()), (1, 1, 2));
+        assert_eq!(app.mode, ViewMode::Songs);
+
+        app.toggle_favorites().await.unwrap();
+        assert!(app.search.is_none());
+        assert_eq!(app.artists.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unliking_in_favorites_keeps_the_row() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_favorites().await.unwrap();
+        app.toggle_star().await.unwrap();
+        assert_eq!(mock.calls_to("unstar")[0].param("id"), Some("s-3"));
+        assert_eq!(app.songs.len(), 2);
+        assert!(app.songs[0].starred.is_none());
+    }
+
+    #[tokio::test]
+    async fn unliking_in_favorites_updates_the_library() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.artists[1].starred = Some("2024-05-01T12:00:00Z".to_string());
+        app.toggle_favorites().await.unwrap();
+        app.mode = ViewMode::Artists;
+        app.toggle_star().await.unwrap();
+        app.toggle_favorites().await.unwrap();
+        assert_eq!(app.artists[1].id, "ar-2");
+        assert!(app.artists[1].starred.is_none());
+    }
+
+    #[tokio::test]
+    async fn favorites_stay_closed_when_get_starred_fails() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_http("getStarred2", 500, 2);
+        assert!(app.toggle_favorites().await.is_err());
+        assert!(app.search.is_none());
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+}
@@ -0,0 +1,264 @@
+use anyhow::Result;
+use std::sync::{atomic::{AtomicUsize, Ordering}, Arc};
+use tokio::task::JoinHandle;
+
+use crate::api::{models::Song, SubsonicClient};
+use super::{take_finished, App, PanelState, ViewMode};
+
+/// Upper bounds for "play this folder recursively", so a click on the
+/// library root doesn't walk the whole server.
+const MAX_FOLDER_SONGS: usize = 2000;
+const MAX_FOLDER_VISITS: usize = 500;
+
+/// One level of the file tree; the root comes from `getIndexes`.
+#[derive(Debug, Clone)]
+pub struct FolderLevel {
+    pub name:    String,
+    pub entries: Vec<Song>,
+    /// Selection to restore when coming back up.
+    pub state:   PanelState,
+}
+
+/// Tracks of a folder being collected in the background (Enter on a folder).
+pub struct FolderWalk {
+    label:    String,
+    /// Tracks found so far, for the status line.
+    found:    Arc<AtomicUsize>,
+    task:     Option<JoinHandle<Result<CollectedSongs>>>,
+}
+
+struct CollectedSongs {
+    songs:     Vec<Song>,
+    /// A limit was hit before the whole folder was walked.
+    truncated: bool,
+}
+
+impl App {
+    /// Shift+D – browse the library by its folders instead of its tags.
+    pub async fn toggle_folders(&mut self) -> Result<()> {
+        if self.mode == ViewMode::Folders {
+            self.mode = ViewMode::Artists;
+            return Ok(());
+        }
+        self.close_search();
+        let entries = self.client.get_indexes().await?;
+        self.folder_path  = vec![FolderLevel { name: "/".to_string(), entries, state: PanelState::default() }];
+        self.folder_state = PanelState::default();
+        self.mode         = ViewMode::Folders;
+        Ok(())
+    }
+
+    /// Entries of the folder being shown.
+    pub fn folder_entries(&self) -> &[Song] {
+        self.folder_path.last().map(|l| l.entries.as_slice()).unwrap_or(&[])
+    }
+
+    /// → on a folder: descend into it.
+    pub async fn open_selected_folder(&mut self) -> Result<()> {
+        let Some(entry) = self.folder_entries().get(self.folder_state.selected).cloned() else { return Ok(()); };
+        if !entry.is_dir { return Ok(()); }
+        let directory = self.client.get_music_directory(&entry.id).await?;
+        if let Some(level) = self.folder_path.last_mut() {
+            level.state = self.folder_state;
+        }
+        self.folder_path.push(FolderLevel {
+            name:    entry.title,
+            entries: directory.child,
+            state:   PanelState::default(),
+        });
+        self.folder_state = PanelState::default();
+        Ok(())
+    }
+
+    /// ← in the folder view: back to the parent folder; `false` at the root.
+    pub fn close_folder(&mut self) -> bool {
+        if self.folder_path.len() < 2 { return false; }
+        self.folder_path.pop();
+        self.folder_state = self.folder_path.last().map(|l| l.state).unwrap_or_default();
+        true
+    }
+
+    /// "/Abba/Arrival" for the title bar.
+    pub fn folder_breadcrumb(&self) -> String {
+        let names: Vec<&str> = self.folder_path.iter().skip(1).map(|l| l.name.as_str()).collect();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Enter: on a folder, play everything below it once it has been walked
+    /// in the background; on a track, play the folder's tracks starting with that one.
+    pub async fn play_selected_folder_entry(&mut self) -> Result<()> {
+        let Some(entry) = self.folder_entries().get(self.folder_state.selected).cloned() else { return Ok(()); };
+        if entry.is_dir {
+            self.start_folder_walk(entry.id, entry.title);
+            return Ok(());
+        }
+        let tracks: Vec<Song> = self.folder_entries().iter().filter(|e| !e.is_dir).cloned().collect();
+        let start = tracks.iter().position(|t| t.id == entry.id).unwrap_or(0);
+        let name  = self.folder_path.last().map(|l| l.name.clone()).unwrap_or_default();
+        self.play_folder_songs(tracks, start, &name, "").await
+    }
+
+    fn start_folder_walk(&mut self, id: String, label: String) {
+        if let Some(task) = self.folder_walk.take().and_then(|w| w.task) {
+            task.abort();
+        }
+        let found  = Arc::new(AtomicUsize::new(0));
+        let client = self.client.clone();
+        let count  = found.clone();
+        let task   = tokio::spawn(async move { collect_folder_songs(&client, &id, &count).await });
+        self.status_message = format!("📁 Collecting tracks in {}…", label);
+        self.folder_walk    = Some(FolderWalk { label, found, task: Some(task) });
+    }
+
+    /// Called every UI tick: shows the walk's progress and plays its tracks once it's done.
+    pub async fn folder_walk_tick(&mut self) -> Result<()> {
+        let Some(walk) = self.folder_walk.as_mut() else { return Ok(()); };
+        let Some(result) = take_finished(&mut walk.task).await else {
+            self.status_message = format!("📁 Collecting tracks in {}… {}",
+                walk.label, walk.found.load(Ordering::Relaxed));
+            return Ok(());
+        };
+        let label = walk.label.clone();
+        self.folder_walk = None;
+        let collected = result?;
+        let note = if collected.truncated {
+            format!(" – stopped at the first {}", collected.songs.len())
+        } else {
+            String::new()
+        };
+        self.play_folder_songs(collected.songs, 0, &label, &note).await
+    }
+
+    async fn play_folder_songs(&mut self, songs: Vec<Song>, start: usize, label: &str, note: &str) -> Result<()> {
+        if songs.is_empty() {
+            self.status_message = format!("📁 No tracks in {}", label);
+            return Ok(());
+        }
+        let count = songs.len();
+        self.songs               = songs;
+        self.song_state          = PanelState::default();
+        self.song_state.selected = start;
+        self.is_shuffle          = false;
+        self.start_playback().await?;
+        self.status_message = format!("📁 Playing {} ({} tracks{})", label, count, note);
+        Ok(())
+    }
+}
+
+/// Tracks below `id`, depth first in the server's order; `found` counts them as they come in.
+async fn collect_folder_songs(client: &SubsonicClient, id: &str, found: &AtomicUsize) -> Result<CollectedSongs> {
+    let mut songs   = Vec::new();
+    let mut pending = vec![id.to_string()];
+    let mut visits  = 0;
+    while let Some(dir_id) = pending.pop() {
+        if visits >= MAX_FOLDER_VISITS || songs.len() >= MAX_FOLDER_SONGS {
+            pending.push(dir_id);
+            break;
+        }
+        visits += 1;
+        let (dirs, tracks): (Vec<Song>, Vec<Song>) = client.get_music_directory(&dir_id).await?
+            .child
+            .into_iter()
+            .partition(|c| c.is_dir);
+        songs.extend(tracks);
+        found.store(songs.len(), Ordering::Relaxed);
+        pending.extend(dirs.into_iter().rev().map(|d| d.id));
+    }
+    let truncated = !pending.is_empty() || songs.len() > MAX_FOLDER_SONGS;
+    songs.truncate(MAX_FOLDER_SONGS);
+    Ok(CollectedSongs { songs, truncated })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    fn ids(songs: &[Song]) -> Vec<&str> {
+        songs.iter().map(|s| s.id.as_str()).collect()
+    }
+
+    /// Runs UI ticks until the background walk has been played.
+    async fn finish_walk(app: &mut App) -> Result<()> {
+        while app.folder_walk.is_some() {
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            app.folder_walk_tick().await?;
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn folders_descend_and_come_back_up() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_folders().await.unwrap();
+        let root: Vec<_> = app.folder_entries().iter().map(|e| (e.title.as_str(), e.is_dir)).collect();
+        assert_eq!(root, [("Abba", true), ("Unsorted", true), ("Loose track", false)]);
+
+        app.open_selected_folder().await.unwrap();
+        assert_eq!(app.folder_breadcrumb(), "/Abba");
+        assert_eq!(app.folder_entries().len(), 3);
+        assert!(app.close_folder());
+        assert_eq!(app.folder_breadcrumb(), "/");
+        assert!(!app.close_folder());
+    }
+
+    #[tokio::test]
+    async fn track_plays_the_folders_tracks_from_there() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_folders().await.unwrap();
+        app.open_selected_folder().await.unwrap();
+        app.on_down();
+        app.on_down();
+        app.play_selected_folder_entry().await.unwrap();
+        assert_eq!(ids(&app.songs), ["f-1"]);
+    }
+
+    #[tokio::test]
+    async fn folder_is_played_depth_first() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_folders().await.unwrap();
+        app.play_selected_folder_entry().await.unwrap();
+        assert!(app.songs.is_empty()); // still walking
+        assert!(app.status_message.contains("Collecting tracks in Abba"), "{}", app.status_message);
+        finish_walk(&mut app).await.unwrap();
+        assert_eq!(ids(&app.songs), ["f-1", "s-1", "s-2", "s-3"]);
+        assert!(app.status_message.contains("Abba (4 tracks)"), "{}", app.status_message);
+        assert_eq!(mock.calls_to("getMusicDirectory").len(), 3);
+    }
+
+    #[tokio::test]
+    async fn failed_directory_stays_in_the_parent() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_folders().await.unwrap();
+        mock.fail_with("getMusicDirectory", 70, "Directory not found");
+        assert!(app.open_selected_folder().await.is_err());
+        assert_eq!(app.folder_breadcrumb(), "/");
+        app.play_selected_folder_entry().await.unwrap();
+        assert!(finish_walk(&mut app).await.is_err());
+        assert!(app.songs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn endless_folder_walk_is_cut_off_and_reported() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_folders().await.unwrap();
+        // Every folder contains one track and itself again
+        mock.respond_with("getMusicDirectory", serde_json::json!({"directory": {"id": "loop", "name": "Loop", "child": [
+            {"id": "loop", "title": "Loop", "isDir": true},
+            {"id": "t-1", "title": "Track", "isDir": false},
+        ]}}));
+        app.play_selected_folder_entry().await.unwrap();
+        finish_walk(&mut app).await.unwrap();
+        assert_eq!(app.songs.len(), MAX_FOLDER_VISITS);
+        assert_eq!(mock.calls_to("getMusicDirectory").len(), MAX_FOLDER_VISITS);
+        assert!(app.status_message.contains("stopped at the first 500"), "{}", app.status_message);
+    }
+
+    #[tokio::test]
+    async fn failed_index_opens_no_folder_view() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getIndexes", 0, "Scan in progress");
+        assert!(app.toggle_folders().await.is_err());
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+}
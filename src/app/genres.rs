@@ -0,0 +1,114 @@
+use anyhow::Result;
+
+use crate::api::models::AlbumListType;
+use super::{App, PanelState, ViewMode};
+
+/// Songs queued when a whole genre is played (`getSongsByGenre` caps `count` at 500).
+const GENRE_SONG_LIMIT: u32 = 500;
+
+impl App {
+    /// Shift+G – toggles the genre browser, loading the genre list on first use.
+    pub async fn toggle_genres(&mut self) -> Result<()> {
+        self.close_search();
+        if matches!(self.mode, ViewMode::Genres) || self.current_genre.is_some() {
+            self.current_genre = None;
+            self.album_list    = None;
+            self.albums.clear();
+            self.album_state   = PanelState::default();
+            self.mode          = ViewMode::Artists;
+            return Ok(());
+        }
+        if self.genres.is_empty() {
+            let mut genres = self.client.get_genres().await?;
+            genres.sort_by_key(|g| g.value.to_lowercase());
+            self.genres = genres;
+        }
+        self.album_list    = None;
+        self.current_album = None;
+        self.albums.clear();
+        self.album_state   = PanelState::default();
+        self.mode          = ViewMode::Genres;
+        Ok(())
+    }
+
+    /// → on a genre: its albums, paged like the album browser.
+    pub async fn open_genre_albums(&mut self) -> Result<()> {
+        let Some(genre) = self.genres.get(self.genre_state.selected).map(|g| g.value.clone()) else {
+            return Ok(());
+        };
+        self.open_album_list(AlbumListType::ByGenre(genre.clone())).await?;
+        self.current_genre = Some(genre);
+        Ok(())
+    }
+
+    /// Enter on a genre: queue its songs and start playing.
+    pub async fn play_genre(&mut self) -> Result<()> {
+        let Some(genre) = self.genres.get(self.genre_state.selected).map(|g| g.value.clone()) else {
+            return Ok(());
+        };
+        let songs = self.client.get_songs_by_genre(&genre, GENRE_SONG_LIMIT, 0).await?;
+        if songs.is_empty() {
+            self.status_message = format!("🎸 No songs in {}", genre);
+            return Ok(());
+        }
+        self.status_message = format!("🎸 {} – {} songs", genre, songs.len());
+        self.songs       = songs;
+        self.song_state  = PanelState::default();
+        self.is_shuffle  = false;
+        self.start_playback().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn genres_are_sorted_and_drill_into_albums() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_genres().await.unwrap();
+        assert_eq!(app.mode, ViewMode::Genres);
+        let names: Vec<_> = app.genres.iter().map(|g| g.value.as_str()).collect();
+        assert_eq!(names, ["Pop", "Rock"]);
+
+        app.genre_state.selected = 1;
+        app.open_genre_albums().await.unwrap();
+        assert_eq!(app.current_genre.as_deref(), Some("Rock"));
+        assert_eq!(mock.calls_to("getAlbumList2")[0].param("genre"), Some("Rock"));
+        app.go_back();
+        assert_eq!(app.mode, ViewMode::Genres);
+    }
+
+    #[tokio::test]
+    async fn playing_a_genre_queues_its_songs() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_genres().await.unwrap();
+        app.genre_state.selected = 1;
+        app.play_genre().await.unwrap();
+        assert_eq!(app.songs.len(), 2);
+        assert_eq!(app.now_playing, Some(0));
+        let call = &mock.calls_to("getSongsByGenre")[0];
+        assert_eq!(call.param("genre"), Some("Rock"));
+        assert_eq!(call.param("count"), Some("500"));
+    }
+
+    #[tokio::test]
+    async fn failed_genre_list_stays_in_the_library() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getGenres", 0, "Scan in progress");
+        assert!(app.toggle_genres().await.is_err());
+        assert_eq!(app.mode, ViewMode::Artists);
+        assert!(app.genres.is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_genre_songs_keep_the_queue() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_genres().await.unwrap();
+        mock.fail_with("getSongsByGenre", 0, "Scan in progress");
+        assert!(app.play_genre().await.is_err());
+        assert!(app.songs.is_empty());
+        assert_eq!(app.now_playing, None);
+    }
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+
+use crate::api::models::AlbumListType;
+use super::{album_list::parse_year_range, App};
+
+/// What the one-line input prompt is asking for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputPurpose {
+    YearRange,
+    Genre,
+    NewPlaylist,
+    RenamePlaylist,
+    NewPodcast,
+    ChatMessage,
+}
+
+impl InputPurpose {
+    pub fn title(&self) -> &'static str {
+        match self {
+            InputPurpose::YearRange      => " Years (e.g. 1990-1999) ",
+            InputPurpose::Genre          => " Genre ",
+            InputPurpose::NewPlaylist    => " New playlist name ",
+            InputPurpose::RenamePlaylist => " Rename playlist ",
+            InputPurpose::NewPodcast     => " Podcast feed URL ",
+            InputPurpose::ChatMessage    => " Chat message ",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InputPrompt {
+    pub purpose: InputPurpose,
+    pub text:    String,
+}
+
+impl InputPrompt {
+    pub fn new(purpose: InputPurpose) -> Self {
+        Self { purpose, text: String::new() }
+    }
+
+    pub fn with_text(purpose: InputPurpose, text: &str) -> Self {
+        Self { purpose, text: text.to_string() }
+    }
+}
+
+impl App {
+    /// Enter in the input prompt.
+    pub async fn submit_input(&mut self) -> Result<()> {
+        let Some(prompt) = self.input.take() else { return Ok(()); };
+        let text = prompt.text.trim().to_string();
+        if text.is_empty() { return Ok(()); }
+        match prompt.purpose {
+            InputPurpose::YearRange => match parse_year_range(&text) {
+                Some((from, to)) => self.open_album_list(AlbumListType::ByYear { from, to }).await?,
+                None => self.status_message = format!("❌ Not a year range: {}", text),
+            },
+            InputPurpose::Genre          => self.open_album_list(AlbumListType::ByGenre(text)).await?,
+            InputPurpose::NewPlaylist    => self.create_playlist(&text).await?,
+            InputPurpose::RenamePlaylist => self.rename_selected_playlist(&text).await?,
+            InputPurpose::NewPodcast     => self.create_podcast_channel(&text).await?,
+            InputPurpose::ChatMessage    => self.post_chat_message(&text).await?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use crate::lyrics::fetch_lyrics;
+use super::{take_finished, App};
+
+/// Lines moved per PageUp/PageDown in unsynced lyrics.
+const SCROLL_STEP: usize = 10;
+
+impl App {
+    /// Shift+Y – shows the lyrics of the playing song in the center column.
+    pub fn toggle_lyrics(&mut self) {
+        self.show_lyrics = !self.show_lyrics;
+        if self.show_lyrics {
+            self.load_lyrics_for_current();
+        }
+    }
+
+    /// Fetches lyrics for the playing song in the background unless they are
+    /// already loaded; a fetch for the previous song is dropped.
+    pub fn load_lyrics_for_current(&mut self) {
+        let Some(song) = self.now_playing.and_then(|i| self.songs.get(i)).cloned() else {
+            self.lyrics         = None;
+            self.lyrics_song_id = None;
+            return;
+        };
+        if self.lyrics_song_id.as_deref() == Some(song.id.as_str()) { return; }
+
+        if let Some(task) = self.lyrics_fetch.take() {
+            task.abort();
+        }
+        self.lyrics         = None;
+        self.lyrics_song_id = None;
+        self.lyrics_scroll  = 0;
+        let client     = self.client.clone();
+        let structured = self.capabilities.synced_lyrics();
+        let dir        = self.client.config().lyrics.dir();
+        self.lyrics_fetch = Some(tokio::spawn(async move {
+            let lyrics = fetch_lyrics(&client, &song, structured, dir.as_deref()).await;
+            (song.id, lyrics)
+        }));
+    }
+
+    pub fn lyrics_loading(&self) -> bool {
+        self.lyrics_fetch.is_some()
+    }
+
+    /// Called every UI tick; shows lyrics once they have arrived. Only a
+    /// successful fetch marks the song as loaded, so a failed one is retried
+    /// the next time the lyrics are opened.
+    pub async fn lyrics_tick(&mut self) {
+        let Some((song_id, result)) = take_finished(&mut self.lyrics_fetch).await else { return; };
+        match result {
+            Ok(lyrics) => {
+                self.lyrics         = lyrics;
+                self.lyrics_song_id = Some(song_id);
+            }
+            Err(e) => self.status_message = format!("❌ Lyrics: {}", e),
+        }
+    }
+
+    /// PageUp/PageDown – synced lyrics follow the song on their own.
+    pub fn scroll_lyrics(&mut self, down: bool) {
+        let Some(lyrics) = self.lyrics.as_ref().filter(|l| !l.synced) else { return; };
+        self.lyrics_scroll = if down {
+            (self.lyrics_scroll + SCROLL_STEP).min(lyrics.lines.len().saturating_sub(1))
+        } else {
+            self.lyrics_scroll.saturating_sub(SCROLL_STEP)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::Ordering, time::Duration};
+
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    /// Ticks until the background fetch has been picked up.
+    async fn finish_fetch(app: &mut App) {
+        while app.lyrics_fetch.is_some() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            app.lyrics_tick().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn lyrics_load_only_while_shown() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        assert!(mock.calls_to("getLyricsBySongId").is_empty());
+
+        app.toggle_lyrics();
+        finish_fetch(&mut app).await;
+        let lyrics = app.lyrics.as_ref().unwrap();
+        assert!(lyrics.synced);
+        assert_eq!(lyrics.current_line(9_000), Some(2));
+    }
+
+    #[tokio::test]
+    async fn track_change_reloads_lyrics() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        app.toggle_lyrics();
+        finish_fetch(&mut app).await;
+
+        // s-2 only has plain lyrics via getLyrics
+        app.player_status.current_index.store(1, Ordering::Release);
+        app.update_now_playing().await;
+        assert!(app.lyrics.is_none()); // not s-1's lyrics while s-2's load
+        finish_fetch(&mut app).await;
+        assert_eq!(app.lyrics_song_id.as_deref(), Some("s-2"));
+        assert_eq!(mock.calls_to("getLyricsBySongId").len(), 2);
+        assert!(!app.lyrics.as_ref().unwrap().synced);
+        app.scroll_lyrics(true);
+        assert_eq!(app.lyrics_scroll, 1);
+    }
+
+    #[tokio::test]
+    async fn failed_lyrics_are_reported() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getLyricsBySongId", 0, "Lyrics plugin crashed");
+        app.songs       = app.client.get_album_songs("1").await.unwrap();
+        app.now_playing = Some(0);
+        app.toggle_lyrics();
+        finish_fetch(&mut app).await;
+        assert!(app.lyrics.is_none());
+        assert!(app.status_message.contains("Lyrics plugin crashed"), "{}", app.status_message);
+
+        // Not marked as loaded – reopening tries again
+        app.toggle_lyrics();
+        app.toggle_lyrics();
+        finish_fetch(&mut app).await;
+        assert!(app.lyrics_song_id.is_none());
+        assert_eq!(mock.calls_to("getLyricsBySongId").len(), 2);
+    }
+}
@@ -1,10 +1,41 @@
+mod album_list;
+mod artist_info;
+mod bookmarks;
+mod chat;
+mod favorites;
+mod folders;
+mod genres;
+mod input;
+mod lyrics;
+mod music_folders;
+mod now_playing;
+mod play_queue;
+mod playlist_edit;
+mod podcasts;
+mod radio;
+mod ratings;
+mod search;
+mod shares;
+mod stations;
+
+pub use album_list::{AlbumListPreset, ALBUM_LIST_PRESETS};
+pub use artist_info::ArtistInfoView;
+pub use bookmarks::{format_position, BookmarkCandidate};
+pub use folders::FolderLevel;
+pub use input::{InputPrompt, InputPurpose};
+pub use music_folders::FolderPicker;
+pub use playlist_edit::PlaylistPicker;
+pub use podcasts::episode_status_label;
+pub use radio::RadioSeed;
+pub use search::SearchState;
+
 use std::{
     fs,
     path::Path,
     process::{Child, Command},
-    sync::Arc,
+    sync::{Arc, Mutex},
     sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering},
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use anyhow::Result;
@@ -14,16 +45,17 @@ use serde_json::Value;
 use tokio::{
     io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
     net::UnixStream,
+    task::JoinHandle,
 };
 
-use crate::api::{build_stream_url, endpoints::*, models::*};
-use crate::config::Config;
+use crate::api::{build_stream_url, models::*, stream_header_lines, ServerCapabilities, SubsonicClient};
 use crate::visual::Visualizer;
 
 // ── ViewMode ─────────────────────────────────────────────────────────────────
 
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum ViewMode {
+    #[default]
     Artists,
     Albums,
     Songs,
@@ -31,10 +63,16 @@ pub enum ViewMode {
     PlaylistSongs,
     Jukebox,
     Visualizer,
-}
-
-impl Default for ViewMode {
-    fn default() -> Self { ViewMode::Artists }
+    AlbumList,
+    Genres,
+    ArtistInfo,
+    Stations,
+    Podcasts,
+    PodcastEpisodes,
+    Bookmarks,
+    Shares,
+    Folders,
+    NowPlaying,
 }
 
 impl ViewMode {
@@ -47,6 +85,16 @@ impl ViewMode {
             ViewMode::Playlists     => ViewMode::Playlists,
             ViewMode::Jukebox       => ViewMode::Jukebox,
             ViewMode::Visualizer    => ViewMode::Visualizer,
+            ViewMode::AlbumList     => ViewMode::AlbumList,
+            ViewMode::Genres        => ViewMode::Genres,
+            ViewMode::ArtistInfo    => ViewMode::ArtistInfo,
+            ViewMode::Stations      => ViewMode::Stations,
+            ViewMode::Podcasts        => ViewMode::Podcasts,
+            ViewMode::PodcastEpisodes => ViewMode::Podcasts,
+            ViewMode::Bookmarks       => ViewMode::Bookmarks,
+            ViewMode::Shares          => ViewMode::Shares,
+            ViewMode::Folders         => ViewMode::Folders,
+            ViewMode::NowPlaying      => ViewMode::NowPlaying,
         }
     }
 }
@@ -72,6 +120,9 @@ pub struct AppState {
     pub current_album:    Option<Album>,
     pub current_playlist: Option<Playlist>,
     pub now_playing:      Option<usize>,
+    /// Selected libraries (`musicFolderId`); empty = all.
+    #[serde(default)]
+    pub music_folders:    Vec<String>,
 }
 
 impl Default for AppState {
@@ -86,6 +137,7 @@ impl Default for AppState {
             current_album:    None,
             current_playlist: None,
             now_playing:      None,
+            music_folders:    Vec::new(),
         }
     }
 }
@@ -101,6 +153,8 @@ pub struct PlayerStatus {
     pub songs:                    AtomicUsize,
     pub current_scrobble_sent:    AtomicBool,
     pub current_now_playing_sent: AtomicBool,
+    /// ICY title reported by mpv while an internet radio station plays.
+    pub stream_title:             Mutex<String>,
 }
 
 // ── App ───────────────────────────────────────────────────────────────────────
@@ -124,10 +178,11 @@ pub struct App {
     pub playlist_state:   PanelState,
     pub now_playing:      Option<usize>,
     pub temp_dir:         Option<tempfile::TempDir>,
-    pub config:           Config,
+    pub client:           SubsonicClient,
+    pub capabilities:     ServerCapabilities,
     pub is_search_mode:   bool,
     pub search_query:     String,
-    pub search_results:   Vec<Song>,
+    pub search:           Option<SearchState>,
     pub player_status:    Arc<PlayerStatus>,
     pub search_history:   Vec<String>,
     pub is_help_mode:     bool,
@@ -137,7 +192,72 @@ pub struct App {
     pub jukebox_trim_offset:    usize,
     pub jukebox_fetching:       bool,
     pub is_shuffle:             bool,
+    /// Set while the jukebox runs as song radio (Shift+R).
+    pub radio_seed:             Option<RadioSeed>,
     pub visualizer:             Visualizer,
+    pub input:                  Option<InputPrompt>,
+    pub album_list:             Option<AlbumListType>,
+    pub album_list_state:       PanelState,
+    pub album_list_exhausted:   bool,
+    pub genres:                 Vec<Genre>,
+    pub genre_state:            PanelState,
+    /// Genre whose albums are shown (→ from the genre browser).
+    pub current_genre:          Option<String>,
+    pub playlist_picker:        Option<PlaylistPicker>,
+    pub folder_picker:          Option<FolderPicker>,
+    /// Playlist id armed by a first Delete press.
+    pub pending_delete:         Option<String>,
+    /// Start position (seconds) for the next `start_playback`.
+    pub resume_at:              Option<u64>,
+    /// `--start` is still set in mpv and must be cleared before the next track.
+    start_reset_pending:        bool,
+    /// Artist info panel (Shift+I) and its similar-artists / top-songs selection.
+    pub artist_info:            Option<ArtistInfoView>,
+    pub artist_info_state:      PanelState,
+    /// Internet radio stations (Shift+T) and the one playing, if any.
+    pub stations:               Vec<InternetRadioStation>,
+    pub station_state:          PanelState,
+    pub current_station:        Option<InternetRadioStation>,
+    /// Podcasts (Shift+O): channels, the "newest" list and their selections.
+    pub podcasts:               Vec<PodcastChannel>,
+    pub newest_episodes:        Vec<PodcastEpisode>,
+    pub podcast_state:          PanelState,
+    pub episode_state:          PanelState,
+    /// Bookmarks view (Shift+B).
+    pub bookmarks:              Vec<Bookmark>,
+    pub bookmark_state:         PanelState,
+    /// Playing track (long one or podcast episode) to bookmark when stopped or skipped.
+    pub bookmark_candidate:     Option<BookmarkCandidate>,
+    /// Share links (Shift+V) and the popup showing a new or selected one.
+    pub shares:                 Vec<Share>,
+    pub share_state:            PanelState,
+    pub share_popup:            Option<Share>,
+    /// Folder browser (Shift+D): root first, the shown folder last.
+    pub folder_path:            Vec<FolderLevel>,
+    pub folder_state:           PanelState,
+    pub folder_walk:            Option<folders::FolderWalk>,
+    /// Other users' tracks (Shift+W), polled while the panel is open.
+    pub now_playing_entries:    Vec<NowPlayingEntry>,
+    pub now_playing_state:      PanelState,
+    pub now_playing_polled:     Option<Instant>,
+    now_playing_poll:           Option<JoinHandle<Result<Vec<NowPlayingEntry>>>>,
+    play_queue_save:            Option<JoinHandle<Result<()>>>,
+    /// Chat overlay (Shift+C); `chat_since` is the newest message's time.
+    pub show_chat:              bool,
+    pub chat_messages:          Vec<ChatMessage>,
+    pub chat_since:             Option<u64>,
+    pub chat_polled:            Option<Instant>,
+    chat_poll:                  Option<JoinHandle<Result<Vec<ChatMessage>>>>,
+    /// Text waiting to be copied to the terminal clipboard (OSC 52) by the main loop.
+    pub clipboard:              Option<String>,
+    /// Lyrics take over the center column (Shift+Y).
+    pub show_lyrics:            bool,
+    pub lyrics:                 Option<crate::lyrics::Lyrics>,
+    /// Song the loaded lyrics belong to.
+    pub lyrics_song_id:         Option<String>,
+    lyrics_fetch:               Option<JoinHandle<(String, Result<Option<crate::lyrics::Lyrics>>)>>,
+    /// First visible line of unsynced lyrics.
+    pub lyrics_scroll:          usize,
 }
 
 impl Drop for App {
@@ -153,13 +273,35 @@ impl Drop for App {
 
 impl App {
     pub async fn new() -> Result<Self> {
-        let config    = crate::config::read_config()?;
-        let artists   = get_artists(&config).await?;
-        let playlists = get_playlists(&config).await.unwrap_or_default();
-        let loaded    = Self::load_state().unwrap_or_default();
+        let client = SubsonicClient::new(crate::config::read_config()?)?;
+        let mut app = Self::with_client(client, Self::load_state().unwrap_or_default()).await;
+        if app.client.config().play_queue.resume_on_start {
+            if let Err(e) = app.resume_play_queue().await {
+                app.status_message = format!("❌ {}", e);
+            }
+        }
+        Ok(app)
+    }
+
+    /// Builds the app around an existing client and restored state.
+    pub async fn with_client(mut client: SubsonicClient, loaded: AppState) -> Self {
+        let capabilities = ServerCapabilities::detect(&client).await;
+        client.set_form_post(capabilities.form_post());
+        client.set_api_version(capabilities.negotiated_api_version());
+        client.set_music_folders(loaded.music_folders);
+
+        // A failed login must not kill the TUI – show the server's error instead
+        let (artists, status_message) = match client.get_artists().await {
+            Ok(artists) => (artists, format!("Connected to {}", capabilities.summary())),
+            Err(e)      => (Vec::new(), format!("❌ {}", e)),
+        };
+        let playlists = client.get_playlists().await.unwrap_or_default();
+        let mut visualizer = Visualizer::new(8);
+        visualizer.set_http_headers(&client.config().server.auth.headers);
 
-        Ok(Self {
-            config,
+        Self {
+            client,
+            capabilities,
             artists,
             albums:           Vec::new(),
             songs:            Vec::new(),
@@ -168,7 +310,7 @@ impl App {
             prev_mode:        loaded.mode,
             should_quit:      false,
             current_player:   None,
-            status_message:   String::new(),
+            status_message,
             current_artist:   loaded.current_artist,
             current_album:    loaded.current_album,
             current_playlist: loaded.current_playlist,
@@ -182,7 +324,7 @@ impl App {
             is_help_mode:     false,
             is_search_mode:   false,
             search_query:     String::new(),
-            search_results:   Vec::new(),
+            search:           None,
             search_history:   Vec::new(),
             player_status:    Arc::new(PlayerStatus {
                 current_index:            AtomicUsize::new(usize::MAX),
@@ -192,14 +334,62 @@ impl App {
                 songs:                    AtomicUsize::new(0),
                 current_scrobble_sent:    AtomicBool::new(false),
                 current_now_playing_sent: AtomicBool::new(false),
+                stream_title:             Mutex::new(String::new()),
             }),
             temp_dir:            None,
             is_jukebox_mode:     false,
             jukebox_trim_offset: 0,
             jukebox_fetching:    false,
             is_shuffle:          false,
-            visualizer:          Visualizer::new(8),
-        })
+            radio_seed:          None,
+            visualizer,
+            input:                None,
+            album_list:           None,
+            album_list_state:     PanelState::default(),
+            album_list_exhausted: false,
+            genres:               Vec::new(),
+            genre_state:          PanelState::default(),
+            current_genre:        None,
+            playlist_picker:      None,
+            folder_picker:        None,
+            pending_delete:       None,
+            resume_at:            None,
+            start_reset_pending:  false,
+            artist_info:          None,
+            artist_info_state:    PanelState::default(),
+            stations:             Vec::new(),
+            station_state:        PanelState::default(),
+            current_station:      None,
+            podcasts:             Vec::new(),
+            newest_episodes:      Vec::new(),
+            podcast_state:        PanelState::default(),
+            episode_state:        PanelState::default(),
+            bookmarks:            Vec::new(),
+            bookmark_state:       PanelState::default(),
+            bookmark_candidate:   None,
+            shares:               Vec::new(),
+            share_state:          PanelState::default(),
+            share_popup:          None,
+            folder_path:          Vec::new(),
+            folder_state:         PanelState::default(),
+            folder_walk:          None,
+            now_playing_entries:  Vec::new(),
+            now_playing_state:    PanelState::default(),
+            now_playing_polled:   None,
+            now_playing_poll:     None,
+            play_queue_save:      None,
+            show_chat:            false,
+            chat_messages:        Vec::new(),
+            chat_since:           None,
+            chat_polled:          None,
+            chat_poll:            None,
+            clipboard:            None,
+            show_lyrics:          false,
+            lyrics:               None,
+            lyrics_song_id:       None,
+            lyrics_fetch:         None,
+            lyrics_scroll:        0,
+        }
     }
 
     pub async fn reset_to_artist_view(&mut self) -> Result<()> {
@@ -235,6 +425,7 @@ impl App {
             current_album:    self.current_album.clone(),
             current_playlist: self.current_playlist.clone(),
             now_playing:      self.now_playing,
+            music_folders:    self.client.music_folders().to_vec(),
         };
         fs::write(Self::state_file_path(), serde_json::to_string(&state)?)?;
         Ok(())
@@ -260,9 +451,46 @@ impl App {
             ViewMode::PlaylistSongs => &mut self.song_state,
             ViewMode::Jukebox       => &mut self.song_state,
             ViewMode::Visualizer    => &mut self.song_state,
+            ViewMode::AlbumList     => &mut self.album_list_state,
+            ViewMode::Genres        => &mut self.genre_state,
+            ViewMode::ArtistInfo    => &mut self.artist_info_state,
+            ViewMode::Stations      => &mut self.station_state,
+            ViewMode::Podcasts        => &mut self.podcast_state,
+            ViewMode::PodcastEpisodes => &mut self.episode_state,
+            ViewMode::Bookmarks       => &mut self.bookmark_state,
+            ViewMode::Shares          => &mut self.share_state,
+            ViewMode::Folders         => &mut self.folder_state,
+            ViewMode::NowPlaying      => &mut self.now_playing_state,
         }
     }
 
+    /// The song actions (like, rate, add to playlist) apply to: the playing
+    /// song in the jukebox and visualizer, the selected one elsewhere.
+    pub fn selected_song(&self) -> Option<&Song> {
+        match self.mode {
+            ViewMode::Jukebox | ViewMode::Visualizer =>
+                self.songs.get(self.player_status.current_index.load(Ordering::Acquire)),
+            _ => self.songs.get(self.song_state.selected),
+        }
+    }
+
+    /// ← – one level up, taking into account where the current list came from.
+    pub fn go_back(&mut self) {
+        if self.mode == ViewMode::ArtistInfo {
+            self.close_artist_info();
+            return;
+        }
+        if self.mode == ViewMode::Folders {
+            self.close_folder();
+            return;
+        }
+        self.mode = match self.mode {
+            ViewMode::Albums if self.current_genre.is_some() => ViewMode::Genres,
+            ViewMode::Albums if self.album_list.is_some()    => ViewMode::AlbumList,
+            other                                         => other.previous(),
+        };
+    }
+
     pub fn on_down(&mut self) {
         match self.mode {
             ViewMode::Artists => {
@@ -293,6 +521,77 @@ impl App {
                     self.adjust_playlist_scroll();
                 }
             }
+            ViewMode::AlbumList => {
+                let max = ALBUM_LIST_PRESETS.len().saturating_sub(1);
+                if self.album_list_state.selected < max {
+                    self.album_list_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Genres => {
+                let max = self.genres.len().saturating_sub(1);
+                if self.genre_state.selected < max {
+                    self.genre_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::ArtistInfo => {
+                let max = self.artist_info.as_ref().map_or(0, |v| v.entries()).saturating_sub(1);
+                if self.artist_info_state.selected < max {
+                    self.artist_info_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Stations => {
+                let max = self.stations.len().saturating_sub(1);
+                if self.station_state.selected < max {
+                    self.station_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Podcasts => {
+                // Index 0 is the "newest episodes" entry
+                if self.podcast_state.selected < self.podcasts.len() {
+                    self.podcast_state.selected += 1;
+                    self.episode_state = PanelState::default();
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::PodcastEpisodes => {
+                let max = self.podcast_episodes().len().saturating_sub(1);
+                if self.episode_state.selected < max {
+                    self.episode_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Bookmarks => {
+                let max = self.bookmarks.len().saturating_sub(1);
+                if self.bookmark_state.selected < max {
+                    self.bookmark_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Shares => {
+                let max = self.shares.len().saturating_sub(1);
+                if self.share_state.selected < max {
+                    self.share_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Folders => {
+                let max = self.folder_entries().len().saturating_sub(1);
+                if self.folder_state.selected < max {
+                    self.folder_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::NowPlaying => {
+                let max = self.now_playing_entries.len().saturating_sub(1);
+                if self.now_playing_state.selected < max {
+                    self.now_playing_state.selected += 1;
+                    self.adjust_scroll();
+                }
+            }
         }
     }
 
@@ -322,6 +621,67 @@ impl App {
                     self.adjust_playlist_scroll();
                 }
             }
+            ViewMode::AlbumList => {
+                if self.album_list_state.selected > 0 {
+                    self.album_list_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Genres => {
+                if self.genre_state.selected > 0 {
+                    self.genre_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::ArtistInfo => {
+                if self.artist_info_state.selected > 0 {
+                    self.artist_info_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Stations => {
+                if self.station_state.selected > 0 {
+                    self.station_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Podcasts => {
+                if self.podcast_state.selected > 0 {
+                    self.podcast_state.selected -= 1;
+                    self.episode_state = PanelState::default();
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::PodcastEpisodes => {
+                if self.episode_state.selected > 0 {
+                    self.episode_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Bookmarks => {
+                if self.bookmark_state.selected > 0 {
+                    self.bookmark_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Shares => {
+                if self.share_state.selected > 0 {
+                    self.share_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::Folders => {
+                if self.folder_state.selected > 0 {
+                    self.folder_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
+            ViewMode::NowPlaying => {
+                if self.now_playing_state.selected > 0 {
+                    self.now_playing_state.selected -= 1;
+                    self.adjust_scroll();
+                }
+            }
         }
     }
 
@@ -362,20 +722,29 @@ impl App {
         self.now_playing   = None;
         self.album_state   = PanelState::default();
         if let Some(artist) = self.artists.get(self.artist_state.selected) {
-            self.albums         = get_artist_albums(&artist.id, &self.config).await?;
+            self.albums         = self.client.get_artist_albums(&artist.id).await?;
+            // The album column no longer shows search hits
+            if let Some(search) = self.search.as_mut() { search.albums_done = true; }
             self.current_artist = Some(artist.clone());
             self.mode           = ViewMode::Albums;
         }
         Ok(())
     }
 
+    /// ↓ near the end of a paged list (album browser, search results).
+    pub async fn load_more(&mut self) -> Result<()> {
+        self.load_more_albums().await?;
+        self.load_more_search_results().await
+    }
+
     pub async fn load_songs(&mut self) -> Result<()> {
         self.songs.clear();
         self.now_playing = None;
         self.is_shuffle  = false;
         if let Some(album) = self.albums.get(self.album_state.selected) {
-            self.songs         = get_album_songs(&album.id, &self.config).await?;
+            self.songs         = self.client.get_album_songs(&album.id).await?;
             self.current_album = Some(album.clone());
+            if let Some(search) = self.search.as_mut() { search.songs_done = true; }
             self.mode          = ViewMode::Songs;
             self.song_state.selected = 0;
             self.adjust_scroll();
@@ -389,7 +758,7 @@ impl App {
         self.now_playing = None;
         self.is_shuffle  = false;
         if let Some(playlist) = self.playlists.get(self.playlist_state.selected) {
-            self.songs            = get_playlist_songs(&playlist.id, &self.config).await?;
+            self.songs            = self.client.get_playlist_songs(&playlist.id).await?;
             self.current_playlist = Some(playlist.clone());
             self.mode             = ViewMode::PlaylistSongs;
             self.song_state.selected = 0;
@@ -416,18 +785,9 @@ impl App {
     // ── Jukebox ───────────────────────────────────────────────────────────────
 
     pub async fn start_jukebox(&mut self) -> Result<()> {
-        if let Some(mut player) = self.current_player.take() { let _ = player.kill(); }
-        self.is_jukebox_mode     = true;
-        self.jukebox_trim_offset = 0;
-        self.jukebox_fetching    = false;
-        self.is_shuffle          = false;
-        self.current_artist      = None;
-        self.current_album       = None;
-        self.current_playlist    = None;
-        self.albums.clear();
-        self.album_state = PanelState::default();
+        self.enter_jukebox_mode();
         self.status_message = "🎉 Jukebox – Lade Songs…".to_string();
-        let initial = get_random_songs(&self.config, 50).await?;
+        let initial = self.client.get_random_songs(50).await?;
         if initial.is_empty() {
             self.status_message = "Jukebox: Keine Songs gefunden!".to_string();
             return Ok(());
@@ -439,6 +799,21 @@ impl App {
         self.start_playback().await
     }
 
+    /// Common setup of the jukebox and song radio.
+    fn enter_jukebox_mode(&mut self) {
+        if let Some(mut player) = self.current_player.take() { let _ = player.kill(); }
+        self.is_jukebox_mode     = true;
+        self.jukebox_trim_offset = 0;
+        self.jukebox_fetching    = false;
+        self.is_shuffle          = false;
+        self.radio_seed          = None;
+        self.current_artist      = None;
+        self.current_album       = None;
+        self.current_playlist    = None;
+        self.albums.clear();
+        self.album_state = PanelState::default();
+    }
+
     pub async fn jukebox_tick(&mut self) -> Result<()> {
         if !self.is_jukebox_mode { return Ok(()); }
         let current = self.player_status.current_index.load(Ordering::Acquire);
@@ -446,14 +821,18 @@ impl App {
         let total = self.songs.len();
         if !self.jukebox_fetching && total.saturating_sub(current) < 10 {
             self.jukebox_fetching = true;
-            let config        = self.config.clone();
+            let client        = self.client.clone();
             let socket_path   = self.temp_dir
                 .as_ref()
                 .map(|t| t.path().join("mpv.sock").to_str().unwrap_or("").to_string())
                 .unwrap_or_default();
-            let new_songs = get_random_songs(&config, 30).await.unwrap_or_default();
+            let new_songs = if self.radio_seed.is_some() {
+                self.next_radio_batch().await
+            } else {
+                client.get_random_songs(30).await.unwrap_or_default()
+            };
             for song in &new_songs {
-                let url = build_stream_url(&song.id, &config);
+                let url = build_stream_url(&song.id, &client);
                 let cmd = format!("loadfile {} append\n", url);
                 if !socket_path.is_empty() {
                     if let Ok(mut stream) = UnixStream::connect(&socket_path).await {
@@ -492,8 +871,14 @@ impl App {
         self.player_status.force_ui_update.store(true, Ordering::Relaxed);
     }
 
-    pub async fn next_track(&mut self) { self.send_mpv_command("playlist-next\n").await; }
-    pub async fn previous_track(&mut self) { self.send_mpv_command("playlist-prev\n").await; }
+    pub async fn next_track(&mut self) {
+        self.bookmark_playing_track().await;
+        self.send_mpv_command("playlist-next\n").await;
+    }
+    pub async fn previous_track(&mut self) {
+        self.bookmark_playing_track().await;
+        self.send_mpv_command("playlist-prev\n").await;
+    }
 
     pub async fn send_mpv_command(&self, cmd: &str) {
         if let Some(ref temp_dir) = self.temp_dir {
@@ -509,33 +894,47 @@ impl App {
         }
     }
 
-    pub async fn like_current_song(&mut self) -> Result<()> {
-        let current_index = self.player_status.current_index.load(Ordering::Acquire);
-        if current_index == usize::MAX {
-            self.status_message = "❌ No song currently playing".to_string();
+    pub async fn start_playback(&mut self) -> Result<()> {
+        self.bookmark_playing_track().await;
+        let start_index = self.song_state.selected.clamp(0, self.songs.len().saturating_sub(1));
+        let playlist: String = self.songs.iter()
+            .map(|song| build_stream_url(&song.id, &self.client) + "\n")
+            .collect();
+        self.current_station = None;
+        if !self.spawn_player(&playlist, self.songs.len(), start_index, true)? {
             return Ok(());
         }
-        
-        if let Some(song) = self.songs.get_mut(current_index) {
-            match crate::api::endpoints::star_song(&song.id, &self.config).await {
-                Ok(_) => {
-                    // Song als geliked markieren – einfacher String reicht
-                    song.starred = Some("true".to_string());
-                    self.status_message = format!("❤️ Liked: {}", song.title);
-                }
-                Err(e) => {
-                    self.status_message = format!("❌ Failed to like song: {}", e);
-                }
+        self.bookmark_candidate = self.songs.get(start_index).and_then(|s| self.bookmark_candidate_for(s));
+
+        let label = if self.is_jukebox_mode {
+            "🎉 Jukebox / Party Mode".to_string()
+        } else if self.is_shuffle {
+            match self.mode {
+                ViewMode::PlaylistSongs =>
+                    format!("🔀 {}", self.current_playlist.as_ref().map(|p| p.name.as_str()).unwrap_or("")),
+                _ =>
+                    format!("🔀 {}", self.current_album.as_ref().map(|a| a.name.as_str()).unwrap_or("")),
             }
-        }
+        } else {
+            match self.mode {
+                ViewMode::PlaylistSongs =>
+                    self.current_playlist.as_ref().map(|p| p.name.as_str()).unwrap_or("").to_string(),
+                _ =>
+                    self.current_album.as_ref().map(|a| a.name.as_str()).unwrap_or("").to_string(),
+            }
+        };
+        self.status_message = format!("Playing: {}", label);
         Ok(())
-    }     
+    }
 
-    pub async fn start_playback(&mut self) -> Result<()> {
+    /// Starts mpv on `playlist` (one URL per line) plus the IPC monitor that
+    /// follows position, time and stream metadata. `false` if mpv didn't start.
+    /// The configured HTTP headers only go along to our own server's streams.
+    fn spawn_player(&mut self, playlist: &str, entries: usize, start_index: usize, own_server: bool) -> Result<bool> {
         if let Some(mut player) = self.current_player.take() { let _ = player.kill(); }
 
-        let start_index = self.song_state.selected.clamp(0, self.songs.len().saturating_sub(1));
-        self.player_status.songs.store(self.songs.len(), Ordering::Release);
+        self.player_status.songs.store(entries, Ordering::Release);
+        self.player_status.stream_title.lock().unwrap().clear();
         self.player_status.current_index.store(usize::MAX, Ordering::Release);
         self.temp_dir = Some(tempfile::tempdir_in("/tmp")?);
         let socket_path     = self.temp_dir.as_ref().unwrap().path().join("mpv.sock");
@@ -555,32 +954,31 @@ impl App {
             .arg("--msg-level=all=error")
             .arg(format!("--input-ipc-server={}", socket_path_str));
 
-        for song in &self.songs {
-            command.arg(build_stream_url(&song.id, &self.config));
+        // Stream URLs carry credentials – hand them to mpv through files in the
+        // private temp dir instead of argv, where `ps` would show them.
+        let temp_path = self.temp_dir.as_ref().unwrap().path().to_path_buf();
+        let headers   = if own_server { stream_header_lines(self.client.config()) } else { Vec::new() };
+        if !headers.is_empty() {
+            // mpv's `%bytes%value` quoting takes the value verbatim, quotes and all
+            let conf: String = headers.iter()
+                .map(|h| format!("http-header-fields-append=%{}%{}\n", h.len(), h))
+                .collect();
+            let conf_path = temp_path.join("headers.conf");
+            write_private_file(&conf_path, &conf)?;
+            command.arg(format!("--include={}", conf_path.display()));
+        }
+        let playlist_path = temp_path.join("queue.m3u");
+        write_private_file(&playlist_path, playlist)?;
+        command.arg(format!("--playlist={}", playlist_path.display()));
+        if let Some(secs) = self.resume_at.take() {
+            command.arg(format!("--start={}", secs));
+            self.start_reset_pending = true;
+            self.player_status.current_time.store(0, Ordering::Relaxed);
         }
 
         match command.spawn() {
             Ok(child) => {
                 self.current_player = Some(child);
-                let label = if self.is_jukebox_mode {
-                    "🎉 Jukebox / Party Mode".to_string()
-                } else if self.is_shuffle {
-                    match self.mode {
-                        ViewMode::PlaylistSongs =>
-                            format!("🔀 {}", self.current_playlist.as_ref().map(|p| p.name.as_str()).unwrap_or("")),
-                        _ =>
-                            format!("🔀 {}", self.current_album.as_ref().map(|a| a.name.as_str()).unwrap_or("")),
-                    }
-                } else {
-                    match self.mode {
-                        ViewMode::PlaylistSongs =>
-                            self.current_playlist.as_ref().map(|p| p.name.as_str()).unwrap_or("").to_string(),
-                        _ =>
-                            self.current_album.as_ref().map(|a| a.name.as_str()).unwrap_or("").to_string(),
-                    }
-                };
-                self.status_message = format!("Playing: {}", label);
-
                 let status_clone      = self.player_status.clone();
                 let socket_path_clone = socket_path_str.clone();
 
@@ -590,10 +988,11 @@ impl App {
                             Ok(mut stream) => {
                                 let obs_pos  = serde_json::json!({"command": ["observe_property", 1, "playlist-pos"]});
                                 let obs_time = serde_json::json!({"command": ["observe_property", 2, "time-pos"]});
-                                let _ = stream.write_all(obs_pos.to_string().as_bytes()).await;
-                                let _ = stream.write_all(b"\n").await;
-                                let _ = stream.write_all(obs_time.to_string().as_bytes()).await;
-                                let _ = stream.write_all(b"\n").await;
+                                let obs_meta = serde_json::json!({"command": ["observe_property", 3, "metadata"]});
+                                for obs in [obs_pos, obs_time, obs_meta] {
+                                    let _ = stream.write_all(obs.to_string().as_bytes()).await;
+                                    let _ = stream.write_all(b"\n").await;
+                                }
 
                                 let mut buf    = String::new();
                                 let mut reader = BufReader::new(stream);
@@ -618,6 +1017,13 @@ impl App {
                                                         status_clone.current_time.store((t * 1000.0) as u32, Ordering::Relaxed);
                                                     }
                                                 }
+                                                // Internet radio: ICY stream title of the current show / song
+                                                "metadata" => {
+                                                    if let Some(title) = icy_title(data) {
+                                                        *status_clone.stream_title.lock().unwrap() = title;
+                                                        status_clone.force_ui_update.store(true, Ordering::Release);
+                                                    }
+                                                }
                                                 _ => {}
                                             }
                                         }
@@ -632,20 +1038,27 @@ impl App {
                     }
                 });
             }
-            Err(e) => self.status_message = format!("Error starting mpv: {}", e),
+            Err(e) => {
+                self.status_message = format!("Error starting mpv: {}", e);
+                return Ok(false);
+            }
         }
-        Ok(())
+        Ok(true)
     }
 
     pub async fn stop_playback(&mut self) {
+        // Set first, so a failed bookmark update can replace it
+        self.status_message      = "Stopped".to_string();
+        self.bookmark_playing_track().await;
         self.player_status.should_quit.store(true, Ordering::Relaxed);
         if let Some(mut player) = self.current_player.take() { let _ = player.kill(); }
         self.visualizer.stop_ffmpeg_feeder();
-        self.status_message      = "Stopped".to_string();
         self.now_playing         = None;
         self.is_jukebox_mode     = false;
         self.jukebox_trim_offset = 0;
         self.is_shuffle          = false;
+        self.radio_seed          = None;
+        self.current_station     = None;
         self.player_status.current_index.store(usize::MAX, Ordering::Relaxed);
         self.player_status.should_quit.store(false, Ordering::Relaxed);
         self.player_status.force_ui_update.store(true, Ordering::Relaxed);
@@ -656,6 +1069,17 @@ impl App {
         let prev_index    = self.now_playing.unwrap_or(usize::MAX);
         let songs_len     = self.songs.len();
 
+        // `--start` applies to every file – drop it once the resumed track is playing
+        if self.start_reset_pending && current_index < songs_len
+            && self.player_status.current_time.load(Ordering::Relaxed) > 0
+        {
+            self.start_reset_pending = false;
+            self.send_mpv_command("set start none\n").await;
+        }
+
+        // A station isn't part of the song list – don't map mpv's position onto it
+        if self.current_station.is_some() { return; }
+
         if current_index != prev_index {
             if current_index < songs_len {
                 self.player_status.current_scrobble_sent.store(false, Ordering::Release);
@@ -664,13 +1088,28 @@ impl App {
                 self.song_state.selected = current_index;
                 self.adjust_scroll();
                 self.save_state().unwrap_or_else(|e| eprintln!("Failed to save state: {}", e));
+                self.save_play_queue();
+                // Still set for the previous track → it wasn't skipped but played to its end
+                let played_out = self.songs.get(prev_index).zip(self.bookmark_candidate.as_ref())
+                    .is_some_and(|(song, track)| song.id == track.id);
+                if played_out {
+                    self.finish_bookmarked_track().await;
+                }
+                // A resumed bookmark or episode already set up its candidate
+                let song = &self.songs[current_index];
+                if self.bookmark_candidate.as_ref().is_none_or(|track| track.id != song.id) {
+                    self.bookmark_candidate = self.bookmark_candidate_for(song);
+                }
+                if self.show_lyrics {
+                    self.load_lyrics_for_current();
+                }
                 // Restart ffmpeg feeder for new track if visualizer is active.
                 // Always seek to 0 on track change — current_time still holds the
                 // previous song's position and would cause ffmpeg to seek past EOF.
                 if self.mode == ViewMode::Visualizer {
                     if let Some(fifo) = self.visualizer.fifo_path().map(|p| p.to_path_buf()) {
                         if let Some(song) = self.songs.get(current_index) {
-                            let url = build_stream_url(&song.id, &self.config);
+                            let url = build_stream_url(&song.id, &self.client);
                             self.visualizer.start_ffmpeg_feeder(&url, &fifo, 0);
                         }
                     }
@@ -685,11 +1124,17 @@ impl App {
 
     // ── Scrobbling ────────────────────────────────────────────────────────────
 
-    pub async fn check_and_scrobble(&self) {
+    pub async fn check_and_scrobble(&mut self) {
         let current_index = self.player_status.current_index.load(Ordering::Acquire);
-        if current_index == usize::MAX { return; }
+        if current_index == usize::MAX || self.current_station.is_some() { return; }
         let Some(song) = self.songs.get(current_index) else { return };
 
+        if !self.player_status.current_now_playing_sent.swap(true, Ordering::AcqRel) {
+            if let Err(e) = self.client.announce_now_playing(&song.id).await {
+                self.status_message = format!("❌ Now-playing notification failed: {}", e);
+            }
+        }
+
         let current_time_sec   = (self.player_status.current_time.load(Ordering::Relaxed) / 1000) as u64;
         let scrobble_threshold = std::cmp::min(10, song.duration / 2);
 
@@ -698,14 +1143,233 @@ impl App {
         {
             let timestamp_ms = SystemTime::now()
                 .duration_since(UNIX_EPOCH).unwrap().as_millis();
-            if scrobble(&song.id, timestamp_ms, &self.config).await.is_ok() {
-                self.player_status.current_scrobble_sent.store(true, Ordering::Release);
+            // Mark as sent either way – retrying a rejected scrobble every tick only floods the server
+            if let Err(e) = self.client.scrobble(&song.id, timestamp_ms).await {
+                self.status_message = format!("❌ Scrobble failed: {}", e);
             }
+            self.player_status.current_scrobble_sent.store(true, Ordering::Release);
         }
     }
 }
 
+/// Writes `content` to a file only the current user can read (0600).
+fn write_private_file(path: &Path, content: &str) -> Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::OpenOptionsExt;
+    let mut file = fs::OpenOptions::new()
+        .write(true).create(true).truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(content.as_bytes())?;
+    Ok(())
+}
+
+/// `icy-title` from mpv's `metadata` property (keys vary in case between streams).
+fn icy_title(metadata: &Value) -> Option<String> {
+    metadata.as_object()?
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("icy-title"))
+        .and_then(|(_, v)| v.as_str())
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+/// Result of a background task once it has finished. Never waits for one
+/// that is still running, so the UI loop isn't held up by the server.
+async fn take_finished<T>(task: &mut Option<JoinHandle<T>>) -> Option<T> {
+    if !task.as_ref().is_some_and(|t| t.is_finished()) { return None; }
+    task.take()?.await.ok()
+}
+
 pub fn normalize_for_search(s: &str) -> String {
     s.to_ascii_lowercase()
         .replace("ä", "a").replace("ö", "o").replace("ü", "u").replace("ß", "ss")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{mock::{MockCall, MockServer}, API_VERSION};
+
+    /// App started against a fresh mock server – shared by the feature modules' tests.
+    pub(super) async fn app_with_mock() -> (App, MockServer) {
+        app_with_config("").await
+    }
+
+    /// Like `app_with_mock`, with extra config sections (e.g. `"[play_queue]\nsave = false\n"`).
+    pub(super) async fn app_with_config(extra: &str) -> (App, MockServer) {
+        let mock   = MockServer::start().await;
+        let client = SubsonicClient::new(mock.config_with(extra)).expect("mock client");
+        let app    = App::with_client(client, AppState::default()).await;
+        (app, mock)
+    }
+
+    #[tokio::test]
+    async fn startup_loads_artists_and_playlists() {
+        let (app, _mock) = app_with_mock().await;
+        assert_eq!(app.artists.len(), 2);
+        assert_eq!(app.playlists.len(), 1);
+        assert!(app.status_message.starts_with("Connected to mock 0.1.0"));
+    }
+
+    #[tokio::test]
+    async fn handshake_records_capabilities_and_enables_form_post() {
+        let (app, mock) = app_with_mock().await;
+        assert!(app.capabilities.open_subsonic);
+        assert!(app.capabilities.synced_lyrics());
+        // formPost is advertised, so later calls carry no credentials in the URL
+        let call = &mock.calls_to("getArtists")[0];
+        assert_eq!(call.method, "POST");
+        assert_eq!(call.param("u"), Some("tester"));
+        assert_eq!(call.param("v"), Some(API_VERSION));
+        assert_eq!(mock.calls_to("ping")[0].method, "GET");
+    }
+
+    #[tokio::test]
+    async fn older_server_is_spoken_to_in_its_api_version() {
+        let mock = MockServer::start().await;
+        mock.respond_with("ping", serde_json::json!({"version": "1.15.0"}));
+        let app = App::with_client(mock.client(), AppState::default()).await;
+        assert_eq!(mock.calls_to("getArtists")[0].param("v"), Some("1.15.0"));
+        assert!(build_stream_url("s-1", &app.client).contains("v=1.15.0"));
+    }
+
+    #[tokio::test]
+    async fn plain_subsonic_server_gets_no_extensions() {
+        let mock = MockServer::start().await;
+        mock.disable("getOpenSubsonicExtensions");
+        mock.respond_with("ping", serde_json::json!({"openSubsonic": false}));
+        let app = App::with_client(mock.client(), AppState::default()).await;
+        assert!(!app.capabilities.open_subsonic);
+        assert!(!app.capabilities.form_post());
+        assert!(mock.calls_to("getOpenSubsonicExtensions").is_empty());
+        assert_eq!(mock.calls_to("getArtists")[0].method, "GET");
+        assert_eq!(app.artists.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn startup_shows_login_error_instead_of_failing() {
+        let mock = MockServer::start().await;
+        mock.fail_with("getArtists", 40, "Wrong username or password");
+        let app = App::with_client(mock.client(), AppState::default()).await;
+        assert!(app.artists.is_empty());
+        assert!(app.status_message.contains("Wrong username or password"));
+    }
+
+    #[tokio::test]
+    async fn load_albums_uses_selected_artist() {
+        let (mut app, mock) = app_with_mock().await;
+        app.artist_state.selected = 1;
+        app.load_albums().await.unwrap();
+        assert_eq!(app.mode, ViewMode::Albums);
+        assert_eq!(app.albums.len(), 2);
+        assert_eq!(app.current_artist.as_ref().map(|a| a.id.as_str()), Some("ar-2"));
+        assert_eq!(mock.calls_to("getArtist")[0].param("id"), Some("ar-2"));
+    }
+
+    #[tokio::test]
+    async fn failed_album_load_stays_on_the_artist() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getArtist", 70, "Artist not found");
+        assert!(app.load_albums().await.is_err());
+        assert_eq!(app.mode, ViewMode::Artists);
+        assert!(app.albums.is_empty());
+    }
+
+    #[tokio::test]
+    async fn start_jukebox_fills_queue_with_random_songs() {
+        let (mut app, mock) = app_with_mock().await;
+        app.start_jukebox().await.unwrap();
+        assert!(app.is_jukebox_mode);
+        assert_eq!(app.mode, ViewMode::Jukebox);
+        assert_eq!(app.songs.len(), 50);
+        assert_eq!(mock.calls_to("getRandomSongs")[0].param("size"), Some("50"));
+    }
+
+    #[tokio::test]
+    async fn failed_jukebox_start_leaves_the_library() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getRandomSongs", 0, "Database busy");
+        assert!(app.start_jukebox().await.is_err());
+        assert!(app.songs.is_empty());
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+
+    #[tokio::test]
+    async fn queue_is_handed_to_mpv_through_private_playlist() {
+        use std::os::unix::fs::PermissionsExt;
+        let (mut app, _mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.start_playback().await.unwrap();
+
+        let playlist = app.temp_dir.as_ref().unwrap().path().join("queue.m3u");
+        let content  = fs::read_to_string(&playlist).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        assert!(content.lines().all(|l| l.contains("/rest/stream?id=s-")));
+        assert_eq!(fs::metadata(&playlist).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    fn submissions(mock: &MockServer) -> Vec<MockCall> {
+        mock.calls_to("scrobble").into_iter()
+            .filter(|c| c.param("submission") == Some("true"))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn playing_song_is_announced_right_away() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.player_status.current_time.store(5_000, Ordering::Relaxed);
+        app.check_and_scrobble().await;
+        app.check_and_scrobble().await;
+        let calls = mock.calls_to("scrobble");
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].param("id"), Some("s-1"));
+        assert_eq!(calls[0].param("submission"), Some("false"));
+    }
+
+    #[tokio::test]
+    async fn failed_announcement_is_reported() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("scrobble", 0, "Last.fm unreachable");
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.check_and_scrobble().await;
+        assert!(app.status_message.contains("Now-playing notification failed"), "{}", app.status_message);
+    }
+
+    #[tokio::test]
+    async fn scrobble_is_sent_once_past_threshold() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.player_status.current_time.store(5_000, Ordering::Relaxed);
+        app.check_and_scrobble().await;
+        assert!(submissions(&mock).is_empty());
+
+        app.player_status.current_time.store(12_000, Ordering::Relaxed);
+        app.check_and_scrobble().await;
+        app.check_and_scrobble().await;
+        let sent = submissions(&mock);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].param("id"), Some("s-1"));
+    }
+
+    #[tokio::test]
+    async fn stream_headers_reach_mpv_verbatim() {
+        let (mut app, _mock) = app_with_config("[server.auth.headers]\nX-Token = 'say \"hi\"'\n").await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.start_playback().await.unwrap();
+
+        let conf = fs::read_to_string(app.temp_dir.as_ref().unwrap().path().join("headers.conf")).unwrap();
+        assert_eq!(conf, "http-header-fields-append=%17%X-Token: say \"hi\"\n");
+    }
+
+    #[test]
+    fn icy_title_is_read_case_insensitively() {
+        let meta = serde_json::json!({"icy-name": "FIP", "ICY-TITLE": " Miles Davis - So What "});
+        assert_eq!(icy_title(&meta).as_deref(), Some("Miles Davis - So What"));
+        assert_eq!(icy_title(&serde_json::json!({"title": "Song"})), None);
+    }
+}
@@ -0,0 +1,162 @@
+use anyhow::Result;
+
+use crate::api::models::MusicFolder;
+use super::{App, PanelState, ViewMode};
+
+/// Shift+M overlay: the server's libraries, each with a check box.
+/// Nothing checked means all libraries.
+#[derive(Debug, Clone)]
+pub struct FolderPicker {
+    pub folders:  Vec<MusicFolder>,
+    pub chosen:   Vec<String>,
+    pub selected: usize,
+    /// Only one library can be checked – the server ignores further ones.
+    pub single:   bool,
+}
+
+impl FolderPicker {
+    pub fn is_chosen(&self, folder: &MusicFolder) -> bool {
+        self.chosen.contains(&folder.id)
+    }
+}
+
+impl App {
+    /// Shift+M – pick the libraries to browse, search and party in.
+    pub async fn open_folder_picker(&mut self) -> Result<()> {
+        let folders = self.client.get_music_folders().await?;
+        if folders.len() < 2 {
+            self.status_message = "📚 The server has only one library".to_string();
+            return Ok(());
+        }
+        let single     = !self.capabilities.multiple_music_folders();
+        let mut chosen = self.client.music_folders().to_vec();
+        if single {
+            chosen.truncate(1);
+        }
+        self.folder_picker = Some(FolderPicker { folders, chosen, selected: 0, single });
+        Ok(())
+    }
+
+    pub fn folder_picker_up(&mut self) {
+        if let Some(picker) = self.folder_picker.as_mut() {
+            picker.selected = picker.selected.saturating_sub(1);
+        }
+    }
+
+    pub fn folder_picker_down(&mut self) {
+        if let Some(picker) = self.folder_picker.as_mut() {
+            picker.selected = (picker.selected + 1).min(picker.folders.len().saturating_sub(1));
+        }
+    }
+
+    /// Space in the picker.
+    pub fn toggle_picked_folder(&mut self) {
+        let Some(picker) = self.folder_picker.as_mut() else { return; };
+        let Some(folder) = picker.folders.get(picker.selected) else { return; };
+        match picker.chosen.iter().position(|id| *id == folder.id) {
+            Some(pos)             => { picker.chosen.remove(pos); }
+            None if picker.single => picker.chosen = vec![folder.id.clone()],
+            None                  => picker.chosen.push(folder.id.clone()),
+        }
+    }
+
+    /// Enter in the picker: scope the client and reload the artist list.
+    pub async fn apply_folder_picker(&mut self) -> Result<()> {
+        let Some(picker) = self.folder_picker.take() else { return Ok(()); };
+        let chosen: Vec<&MusicFolder> = picker.folders.iter().filter(|f| picker.is_chosen(f)).collect();
+        let all    = chosen.is_empty() || chosen.len() == picker.folders.len();
+        let label  = if all {
+            "all libraries".to_string()
+        } else {
+            chosen.iter().map(|f| f.name.as_str()).collect::<Vec<_>>().join(", ")
+        };
+        let ids = if all { Vec::new() } else { chosen.iter().map(|f| f.id.clone()).collect() };
+        self.set_music_folders(ids).await?;
+        self.status_message = format!("📚 Library: {}", label);
+        Ok(())
+    }
+
+    async fn set_music_folders(&mut self, ids: Vec<String>) -> Result<()> {
+        self.client.set_music_folders(ids);
+        self.close_search();
+        self.artists        = self.client.get_artists().await?;
+        self.artist_state   = PanelState::default();
+        self.album_state    = PanelState::default();
+        self.current_artist = None;
+        self.current_album  = None;
+        self.current_genre  = None;
+        self.album_list     = None;
+        self.albums.clear();
+        self.mode = ViewMode::Artists;
+        self.save_state().unwrap_or_else(|e| eprintln!("Failed to save state: {}", e));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use crate::api::models::SearchPage;
+
+    #[tokio::test]
+    async fn picked_library_scopes_artists_search_and_random_songs() {
+        let (mut app, mock) = app_with_mock().await;
+        app.open_folder_picker().await.unwrap();
+        app.folder_picker_down();
+        app.toggle_picked_folder();
+        app.apply_folder_picker().await.unwrap();
+        assert_eq!(app.client.music_folders(), ["2"]);
+        assert!(app.status_message.contains("Audiobooks"), "{}", app.status_message);
+
+        let artists = mock.calls_to("getArtists");
+        assert_eq!(artists[0].param("musicFolderId"), None);
+        assert_eq!(artists[1].param("musicFolderId"), Some("2"));
+        app.client.get_random_songs(5).await.unwrap();
+        assert_eq!(mock.calls_to("getRandomSongs")[0].param("musicFolderId"), Some("2"));
+        app.client.search("abba", &SearchPage::default()).await.unwrap();
+        assert_eq!(mock.calls_to("search3")[0].param("musicFolderId"), Some("2"));
+    }
+
+    #[tokio::test]
+    async fn checking_every_library_removes_the_scope() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.capabilities.server_type = Some("navidrome".to_string());
+        app.client.set_music_folders(vec!["2".to_string()]);
+        app.open_folder_picker().await.unwrap();
+        app.toggle_picked_folder();
+        app.apply_folder_picker().await.unwrap();
+        assert!(app.client.music_folders().is_empty());
+        assert!(app.status_message.contains("all libraries"));
+    }
+
+    #[tokio::test]
+    async fn other_servers_get_one_library_at_a_time() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.client.set_music_folders(vec!["2".to_string()]);
+        app.open_folder_picker().await.unwrap();
+        assert!(app.folder_picker.as_ref().unwrap().single);
+        app.toggle_picked_folder();
+        assert_eq!(app.folder_picker.as_ref().unwrap().chosen, ["1"]);
+        app.apply_folder_picker().await.unwrap();
+        assert_eq!(app.client.music_folders(), ["1"]);
+    }
+
+    #[tokio::test]
+    async fn single_library_needs_no_picker() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.respond_with("getMusicFolders", serde_json::json!({"musicFolders": {"musicFolder": [
+            {"id": 1, "name": "Music"},
+        ]}}));
+        app.open_folder_picker().await.unwrap();
+        assert!(app.folder_picker.is_none());
+        assert!(app.status_message.contains("only one library"));
+    }
+
+    #[tokio::test]
+    async fn failed_folder_list_opens_no_picker() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getMusicFolders", 50, "Not allowed");
+        assert!(app.open_folder_picker().await.is_err());
+        assert!(app.folder_picker.is_none());
+    }
+}
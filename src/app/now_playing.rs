@@ -0,0 +1,155 @@
+use anyhow::Result;
+use std::{sync::atomic::Ordering, time::{Duration, Instant}};
+
+use crate::api::{build_stream_url, models::NowPlayingEntry};
+use super::{take_finished, App, PanelState, ViewMode};
+
+/// How often the open panel asks the server again.
+const NOW_PLAYING_POLL: Duration = Duration::from_secs(10);
+
+impl App {
+    /// Shift+W – what everyone on the server is listening to right now.
+    pub async fn toggle_now_playing(&mut self) -> Result<()> {
+        if self.mode == ViewMode::NowPlaying {
+            self.mode = ViewMode::Artists;
+            return Ok(());
+        }
+        self.close_search();
+        self.refresh_now_playing().await?;
+        self.now_playing_state = PanelState::default();
+        self.mode              = ViewMode::NowPlaying;
+        Ok(())
+    }
+
+    async fn refresh_now_playing(&mut self) -> Result<()> {
+        let entries = self.client.get_now_playing().await?;
+        self.now_playing_polled = Some(Instant::now());
+        self.set_now_playing_entries(entries);
+        Ok(())
+    }
+
+    fn set_now_playing_entries(&mut self, entries: Vec<NowPlayingEntry>) {
+        self.now_playing_entries = entries;
+        self.now_playing_state.selected = self.now_playing_state.selected
+            .min(self.now_playing_entries.len().saturating_sub(1));
+    }
+
+    /// Called every UI tick; polls in the background while the panel is open.
+    pub async fn now_playing_tick(&mut self) {
+        if let Some(result) = take_finished(&mut self.now_playing_poll).await {
+            match result {
+                Ok(entries) => self.set_now_playing_entries(entries),
+                Err(e)      => self.status_message = format!("❌ Now playing: {}", e),
+            }
+        }
+        if self.mode != ViewMode::NowPlaying || self.now_playing_poll.is_some() { return; }
+        if self.now_playing_polled.is_some_and(|t| t.elapsed() < NOW_PLAYING_POLL) { return; }
+        self.now_playing_polled = Some(Instant::now());
+        let client = self.client.clone();
+        self.now_playing_poll = Some(tokio::spawn(async move { client.get_now_playing().await }));
+    }
+
+    /// Enter on an entry: append the track to the running queue, or play it
+    /// if nothing is playing.
+    pub async fn queue_now_playing_entry(&mut self) -> Result<()> {
+        let Some(entry) = self.now_playing_entries.get(self.now_playing_state.selected).cloned() else {
+            return Ok(());
+        };
+        let song = entry.song;
+        // mpv may have quit at the end of the queue while `current_player` is still set
+        let running = self.current_player.as_mut().is_some_and(|p| matches!(p.try_wait(), Ok(None)));
+        if running && self.current_station.is_none() {
+            let url = build_stream_url(&song.id, &self.client);
+            self.send_mpv_command(&format!("loadfile {} append\n", url)).await;
+            self.status_message = format!("➕ Queued {} (from {})", song.title, entry.username);
+            self.songs.push(song);
+            self.player_status.songs.store(self.songs.len(), Ordering::Release);
+        } else {
+            self.songs      = vec![song.clone()];
+            self.song_state = PanelState::default();
+            self.is_shuffle = false;
+            self.start_playback().await?;
+            self.status_message = format!("▶ {} (from {})", song.title, entry.username);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn panel_polls_only_while_open() {
+        let (mut app, mock) = app_with_mock().await;
+        app.now_playing_tick().await;
+        assert!(mock.calls_to("getNowPlaying").is_empty());
+
+        app.toggle_now_playing().await.unwrap();
+        assert_eq!(app.now_playing_entries[0].username, "anna");
+        assert_eq!(app.now_playing_entries[0].player_name.as_deref(), Some("Feishin"));
+        app.now_playing_tick().await;
+        assert_eq!(mock.calls_to("getNowPlaying").len(), 1); // polled seconds ago
+    }
+
+    /// Ticks until the background poll has been picked up.
+    async fn finish_poll(app: &mut App) {
+        app.now_playing_polled = None;
+        app.now_playing_tick().await;
+        while app.now_playing_poll.is_some() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            app.now_playing_tick().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn open_panel_is_refreshed_in_the_background() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_now_playing().await.unwrap();
+        app.now_playing_entries.clear();
+        finish_poll(&mut app).await;
+        assert_eq!(mock.calls_to("getNowPlaying").len(), 2);
+        assert_eq!(app.now_playing_entries[0].username, "anna");
+    }
+
+    #[tokio::test]
+    async fn failed_background_poll_is_reported() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_now_playing().await.unwrap();
+        mock.fail_with("getNowPlaying", 50, "Not allowed");
+        finish_poll(&mut app).await;
+        assert!(app.status_message.contains("Not allowed"), "{}", app.status_message);
+        assert_eq!(app.now_playing_entries.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn entry_is_played_when_nothing_else_is() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_now_playing().await.unwrap();
+        app.queue_now_playing_entry().await.unwrap();
+        assert_eq!(app.songs[0].id, "s-3");
+        assert!(app.status_message.contains("from anna"), "{}", app.status_message);
+    }
+
+    #[tokio::test]
+    async fn entry_is_played_when_mpv_has_exited() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        let mut finished = std::process::Command::new("true").spawn().unwrap();
+        finished.wait().unwrap();
+        app.current_player = Some(finished);
+        app.toggle_now_playing().await.unwrap();
+        app.queue_now_playing_entry().await.unwrap();
+        assert_eq!(app.songs.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(), ["s-3"]);
+        assert!(app.status_message.starts_with("▶"), "{}", app.status_message);
+    }
+
+    #[tokio::test]
+    async fn failed_poll_opens_no_panel() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getNowPlaying", 50, "Not allowed");
+        assert!(app.toggle_now_playing().await.is_err());
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+}
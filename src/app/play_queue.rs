@@ -0,0 +1,166 @@
+use anyhow::Result;
+use std::sync::atomic::Ordering;
+
+use super::{take_finished, App, PanelState, ViewMode};
+
+impl App {
+    /// Song ids and the playing song's id for `savePlayQueue`, if saving is on.
+    fn play_queue_snapshot(&self) -> Option<(Vec<String>, String)> {
+        if !self.client.config().play_queue.save { return None; }
+        let current = self.now_playing.and_then(|i| self.songs.get(i))?;
+        Some((self.songs.iter().map(|s| s.id.clone()).collect(), current.id.clone()))
+    }
+
+    /// Saves the queue in the background after a track change; a save that
+    /// is still running is replaced by the newer queue.
+    pub fn save_play_queue(&mut self) {
+        let Some((song_ids, current)) = self.play_queue_snapshot() else { return; };
+        if let Some(task) = self.play_queue_save.take() {
+            task.abort();
+        }
+        let client = self.client.clone();
+        self.play_queue_save = Some(tokio::spawn(async move {
+            client.save_play_queue(&song_ids, &current, 0).await
+        }));
+    }
+
+    /// Called every UI tick; reports a failed background save.
+    pub async fn play_queue_tick(&mut self) {
+        if let Some(Err(e)) = take_finished(&mut self.play_queue_save).await {
+            self.status_message = format!("❌ Saving play queue failed: {}", e);
+        }
+    }
+
+    /// Shift+Q – remember where we stopped before the player goes away.
+    pub async fn save_play_queue_on_quit(&mut self) -> Result<()> {
+        if let Some(task) = self.play_queue_save.take() {
+            task.abort();
+        }
+        let Some((song_ids, current)) = self.play_queue_snapshot() else { return Ok(()); };
+        let position = self.player_status.current_time.load(Ordering::Relaxed) as u64;
+        self.client.save_play_queue(&song_ids, &current, position).await
+    }
+
+    /// Shift+U (or `resume_on_start`) – continue the queue saved on the server,
+    /// e.g. by a phone app.
+    pub async fn resume_play_queue(&mut self) -> Result<()> {
+        let queue = match self.client.get_play_queue().await? {
+            Some(queue) if !queue.entry.is_empty() => queue,
+            _ => {
+                self.status_message = "⏯ No saved play queue on the server".to_string();
+                return Ok(());
+            }
+        };
+        let index = queue.current.as_deref()
+            .and_then(|id| queue.entry.iter().position(|s| s.id == id))
+            .unwrap_or(0);
+        let status = format!("⏯ Resumed queue{} – {} songs",
+            queue.changed_by.map(|c| format!(" from {}", c)).unwrap_or_default(),
+            queue.entry.len());
+
+        if self.is_jukebox_mode { self.stop_playback().await; }
+        self.songs            = queue.entry;
+        self.song_state       = PanelState { selected: index, scroll: 0 };
+        self.current_album    = None;
+        self.current_playlist = None;
+        self.is_shuffle       = false;
+        self.mode             = ViewMode::Songs;
+        self.adjust_scroll();
+        self.resume_at        = Some(queue.position / 1000);
+        self.start_playback().await?;
+        self.status_message   = status;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::super::tests::{app_with_config, app_with_mock};
+    use super::*;
+
+    /// Ticks until the background save has been picked up.
+    async fn finish_save(app: &mut App) {
+        while app.play_queue_save.is_some() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            app.play_queue_tick().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn queue_is_resumed_at_the_saved_song() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.resume_play_queue().await.unwrap();
+        assert_eq!(app.songs.len(), 2);
+        assert_eq!(app.song_state.selected, 1);
+        assert_eq!(app.mode, ViewMode::Songs);
+        assert!(app.status_message.contains("from phone"));
+    }
+
+    #[tokio::test]
+    async fn missing_queue_is_reported() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.respond_with("getPlayQueue", serde_json::json!({}));
+        app.resume_play_queue().await.unwrap();
+        assert!(app.songs.is_empty());
+        assert!(app.status_message.contains("No saved play queue"));
+    }
+
+    #[tokio::test]
+    async fn failed_resume_keeps_the_library() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getPlayQueue", 0, "Unknown user");
+        assert!(app.resume_play_queue().await.is_err());
+        assert!(app.songs.is_empty());
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+
+    #[tokio::test]
+    async fn queue_is_saved_on_track_change() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        finish_save(&mut app).await;
+        let call = &mock.calls_to("savePlayQueue")[0];
+        let ids: Vec<_> = call.params.iter().filter(|(k, _)| k == "id").map(|(_, v)| v.as_str()).collect();
+        assert_eq!(ids, ["s-1", "s-2"]);
+        assert_eq!(call.param("current"), Some("s-1"));
+        assert_eq!(call.param("position"), Some("0"));
+    }
+
+    #[tokio::test]
+    async fn queue_is_saved_with_position_on_quit() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs       = app.client.get_album_songs("1").await.unwrap();
+        app.now_playing = Some(1);
+        app.player_status.current_time.store(42_000, Ordering::Relaxed);
+        app.save_play_queue_on_quit().await.unwrap();
+        let call = &mock.calls_to("savePlayQueue")[0];
+        assert_eq!(call.param("current"), Some("s-2"));
+        assert_eq!(call.param("position"), Some("42000"));
+    }
+
+    #[tokio::test]
+    async fn queue_saving_can_be_disabled() {
+        let (mut app, mock) = app_with_config("[play_queue]\nsave = false\n").await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        assert!(app.play_queue_save.is_none());
+        assert!(mock.calls_to("savePlayQueue").is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_save_is_reported() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("savePlayQueue", 50, "Not allowed");
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        finish_save(&mut app).await;
+        assert!(app.status_message.contains("Not allowed"), "{}", app.status_message);
+        assert_eq!(app.now_playing, Some(0));
+    }
+}
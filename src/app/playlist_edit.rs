@@ -0,0 +1,356 @@
+use anyhow::Result;
+use std::sync::atomic::Ordering;
+
+use crate::api::models::{Playlist, PlaylistUpdate};
+use super::{App, InputPrompt, InputPurpose, ViewMode};
+
+/// What the playlist picker adds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickerSource {
+    Song,
+    /// Every album of the artist selected in the Artists view.
+    Artist,
+    Album,
+    Queue,
+}
+
+impl PickerSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PickerSource::Song   => "song",
+            PickerSource::Artist => "artist",
+            PickerSource::Album  => "album",
+            PickerSource::Queue  => "queue",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            PickerSource::Song   => PickerSource::Artist,
+            PickerSource::Artist => PickerSource::Album,
+            PickerSource::Album  => PickerSource::Queue,
+            PickerSource::Queue  => PickerSource::Song,
+        }
+    }
+}
+
+/// Shift+P overlay. `selected == playlists.len()` is the "New playlist…" row.
+#[derive(Debug, Clone)]
+pub struct PlaylistPicker {
+    pub source:   PickerSource,
+    pub selected: usize,
+}
+
+impl App {
+    // ── Picker ────────────────────────────────────────────────────────────────
+
+    /// Shift+P – add the selected song / artist / album (or the queue in the jukebox) to a playlist.
+    pub fn open_playlist_picker(&mut self) {
+        let source = match self.mode {
+            ViewMode::Artists                        => PickerSource::Artist,
+            ViewMode::Albums                         => PickerSource::Album,
+            ViewMode::Jukebox | ViewMode::Visualizer => PickerSource::Queue,
+            _                                        => PickerSource::Song,
+        };
+        self.playlist_picker = Some(PlaylistPicker { source, selected: 0 });
+    }
+
+    pub fn picker_up(&mut self) {
+        if let Some(picker) = self.playlist_picker.as_mut() {
+            picker.selected = picker.selected.saturating_sub(1);
+        }
+    }
+
+    pub fn picker_down(&mut self) {
+        let last = self.playlists.len();
+        if let Some(picker) = self.playlist_picker.as_mut() {
+            picker.selected = (picker.selected + 1).min(last);
+        }
+    }
+
+    /// Tab in the picker.
+    pub fn cycle_picker_source(&mut self) {
+        if let Some(picker) = self.playlist_picker.as_mut() {
+            picker.source = picker.source.next();
+        }
+    }
+
+    /// Enter in the picker: add to the highlighted playlist, or ask for a new name.
+    pub async fn confirm_playlist_picker(&mut self) -> Result<()> {
+        let Some(picker) = self.playlist_picker.clone() else { return Ok(()); };
+        let Some(playlist) = self.playlists.get(picker.selected).cloned() else {
+            self.input = Some(InputPrompt::new(InputPurpose::NewPlaylist));
+            return Ok(());
+        };
+        let song_ids = self.picker_song_ids(picker.source).await?;
+        if song_ids.is_empty() {
+            self.status_message = format!("❌ No {} selected", picker.source.label());
+            return Ok(());
+        }
+        self.client.update_playlist(&playlist.id, &PlaylistUpdate {
+            song_ids_to_add: song_ids.clone(),
+            ..PlaylistUpdate::default()
+        }).await?;
+        self.playlist_picker = None;
+        self.status_message  = format!("➕ {} song(s) → {}", song_ids.len(), playlist.name);
+        self.refresh_playlists().await
+    }
+
+    async fn picker_song_ids(&self, source: PickerSource) -> Result<Vec<String>> {
+        Ok(match source {
+            PickerSource::Song   => self.selected_song().map(|s| vec![s.id.clone()]).unwrap_or_default(),
+            PickerSource::Queue  => self.songs.iter().map(|s| s.id.clone()).collect(),
+            PickerSource::Artist => match self.artists.get(self.artist_state.selected) {
+                Some(artist) => {
+                    let mut ids = Vec::new();
+                    for album in self.client.get_artist_albums(&artist.id).await? {
+                        ids.extend(self.client.get_album_songs(&album.id).await?.into_iter().map(|s| s.id));
+                    }
+                    ids
+                }
+                None => Vec::new(),
+            },
+            PickerSource::Album  => match self.albums.get(self.album_state.selected) {
+                Some(album) => self.client.get_album_songs(&album.id).await?
+                    .into_iter().map(|s| s.id).collect(),
+                None => Vec::new(),
+            },
+        })
+    }
+
+    // ── Playlists view ────────────────────────────────────────────────────────
+
+    /// Shift+N – new empty playlist.
+    pub fn start_new_playlist(&mut self) {
+        self.playlist_picker = None;
+        self.input = Some(InputPrompt::new(InputPurpose::NewPlaylist));
+    }
+
+    /// F2 – rename the selected playlist.
+    pub fn start_rename_playlist(&mut self) {
+        if let Some(playlist) = self.playlists.get(self.playlist_state.selected) {
+            self.input = Some(InputPrompt::with_text(InputPurpose::RenamePlaylist, &playlist.name));
+        }
+    }
+
+    /// Creates `name`, filled from the picker's source when the picker is open.
+    pub async fn create_playlist(&mut self, name: &str) -> Result<()> {
+        let song_ids = match self.playlist_picker.take() {
+            Some(picker) => self.picker_song_ids(picker.source).await?,
+            None         => Vec::new(),
+        };
+        self.client.create_playlist(name, &song_ids).await?;
+        self.status_message = format!("📋 Created {} ({} songs)", name, song_ids.len());
+        self.refresh_playlists().await
+    }
+
+    pub async fn rename_selected_playlist(&mut self, name: &str) -> Result<()> {
+        let Some(playlist) = self.playlists.get(self.playlist_state.selected).cloned() else { return Ok(()); };
+        self.client.update_playlist(&playlist.id, &PlaylistUpdate {
+            name: Some(name.to_string()),
+            ..PlaylistUpdate::default()
+        }).await?;
+        self.status_message = format!("✏️ {} → {}", playlist.name, name);
+        self.refresh_playlists().await
+    }
+
+    /// Delete in the playlist list – needs a second press to confirm.
+    pub async fn delete_selected_playlist(&mut self) -> Result<()> {
+        let Some(playlist) = self.playlists.get(self.playlist_state.selected).cloned() else { return Ok(()); };
+        if self.pending_delete.as_deref() != Some(playlist.id.as_str()) {
+            self.status_message = format!("🗑 Press Delete again to delete {}", playlist.name);
+            self.pending_delete = Some(playlist.id);
+            return Ok(());
+        }
+        self.pending_delete = None;
+        self.client.delete_playlist(&playlist.id).await?;
+        self.status_message = format!("🗑 Deleted {}", playlist.name);
+        self.refresh_playlists().await
+    }
+
+    // ── Playlist songs ────────────────────────────────────────────────────────
+
+    /// Delete in `PlaylistSongs` – removes the selected entry.
+    pub async fn remove_selected_from_playlist(&mut self) -> Result<()> {
+        let Some(playlist) = self.editable_playlist() else { return Ok(()); };
+        let index = self.song_state.selected;
+        let Some(song) = self.songs.get(index).cloned() else { return Ok(()); };
+        self.client.update_playlist(&playlist.id, &PlaylistUpdate {
+            song_indexes_to_remove: vec![index],
+            ..PlaylistUpdate::default()
+        }).await?;
+
+        self.songs.remove(index);
+        if self.current_player.is_some() {
+            self.send_mpv_command(&format!("playlist-remove {}\n", index)).await;
+            self.player_status.songs.store(self.songs.len(), Ordering::Release);
+        }
+        self.now_playing = match self.now_playing {
+            Some(i) if i == index => None,
+            Some(i) if i > index  => Some(i - 1),
+            other                 => other,
+        };
+        self.song_state.selected = index.min(self.songs.len().saturating_sub(1));
+        self.adjust_scroll();
+        self.status_message = format!("➖ Removed {}", song.title);
+        self.refresh_playlists().await
+    }
+
+    /// Shift+↑ / Shift+↓ in `PlaylistSongs`.
+    pub async fn move_selected_in_playlist(&mut self, up: bool) -> Result<()> {
+        let Some(playlist) = self.editable_playlist() else { return Ok(()); };
+        let from = self.song_state.selected;
+        let to   = if up { from.checked_sub(1) } else { Some(from + 1).filter(|&i| i < self.songs.len()) };
+        let Some(to) = to else { return Ok(()); };
+
+        self.songs.swap(from, to);
+        let song_ids: Vec<String> = self.songs.iter().map(|s| s.id.clone()).collect();
+        if let Err(e) = self.client.replace_playlist_songs(&playlist.id, &song_ids).await {
+            self.songs.swap(from, to);
+            return Err(e);
+        }
+
+        if self.current_player.is_some() {
+            // Moving the later entry in front of the earlier one swaps both
+            self.send_mpv_command(&format!("playlist-move {} {}\n", from.max(to), from.min(to))).await;
+        }
+        self.now_playing = match self.now_playing {
+            Some(i) if i == from => Some(to),
+            Some(i) if i == to   => Some(from),
+            other                => other,
+        };
+        self.song_state.selected = to;
+        self.adjust_scroll();
+        // Name and song count are unchanged – no need to reload the playlists
+        Ok(())
+    }
+
+    /// The playlist shown in `PlaylistSongs`, if its list can be edited in place.
+    fn editable_playlist(&mut self) -> Option<Playlist> {
+        if self.mode != ViewMode::PlaylistSongs { return None; }
+        if self.is_shuffle {
+            // Positions no longer match the server's order
+            self.status_message = "❌ Reopen the playlist without shuffle to edit it".to_string();
+            return None;
+        }
+        self.current_playlist.clone()
+    }
+
+    /// Reloads `playlists` (names, song counts) after an edit.
+    pub async fn refresh_playlists(&mut self) -> Result<()> {
+        self.playlists = self.client.get_playlists().await?;
+        self.playlist_state.selected = self.playlist_state.selected.min(self.playlists.len().saturating_sub(1));
+        if let Some(current) = self.current_playlist.take() {
+            self.current_playlist = self.playlists.iter().find(|p| p.id == current.id).cloned();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn picker_adds_selected_album_to_playlist() {
+        let (mut app, mock) = app_with_mock().await;
+        app.load_albums().await.unwrap();
+        app.open_playlist_picker();
+        assert_eq!(app.playlist_picker.as_ref().unwrap().source, PickerSource::Album);
+        app.confirm_playlist_picker().await.unwrap();
+        let update = &mock.calls_to("updatePlaylist")[0];
+        assert_eq!(update.param("playlistId"), Some("pl-1"));
+        assert_eq!(update.params.iter().filter(|(k, _)| k == "songIdToAdd").count(), 2);
+        assert!(app.playlist_picker.is_none());
+    }
+
+    #[tokio::test]
+    async fn picker_adds_selected_artist_from_the_artists_view() {
+        let (mut app, mock) = app_with_mock().await;
+        // Albums of another artist, left over from browsing
+        app.albums = app.client.get_artist_albums("ar-2").await.unwrap();
+        app.artist_state.selected = 0;
+        app.open_playlist_picker();
+        assert_eq!(app.playlist_picker.as_ref().unwrap().source, PickerSource::Artist);
+        app.confirm_playlist_picker().await.unwrap();
+        assert_eq!(mock.calls_to("getArtist").last().unwrap().param("id"), Some("ar-1"));
+        let albums: Vec<_> = mock.calls_to("getAlbum").iter().filter_map(|c| c.param("id").map(str::to_string)).collect();
+        assert_eq!(albums, ["1", "2"]);
+        assert_eq!(mock.calls_to("updatePlaylist")[0].params.iter().filter(|(k, _)| k == "songIdToAdd").count(), 4);
+    }
+
+    #[tokio::test]
+    async fn picker_creates_playlist_from_queue() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("2").await.unwrap();
+        app.open_playlist_picker();
+        app.cycle_picker_source();
+        app.cycle_picker_source();
+        app.picker_down();
+        app.confirm_playlist_picker().await.unwrap();
+        assert_eq!(app.input.as_ref().map(|i| i.purpose), Some(InputPurpose::NewPlaylist));
+        app.input.as_mut().unwrap().text = "Road trip".to_string();
+        app.submit_input().await.unwrap();
+        let create = &mock.calls_to("createPlaylist")[0];
+        assert_eq!(create.param("name"), Some("Road trip"));
+        assert_eq!(create.params.iter().filter(|(k, _)| k == "songId").count(), 2);
+        assert_eq!(mock.calls_to("getPlaylists").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_add_keeps_the_picker_open() {
+        let (mut app, mock) = app_with_mock().await;
+        app.load_albums().await.unwrap();
+        mock.fail_with("updatePlaylist", 50, "Not your playlist");
+        app.open_playlist_picker();
+        assert!(app.confirm_playlist_picker().await.is_err());
+        assert!(app.playlist_picker.is_some());
+    }
+
+    #[tokio::test]
+    async fn playlist_songs_can_be_reordered() {
+        let (mut app, mock) = app_with_mock().await;
+        app.load_playlist_songs().await.unwrap();
+        app.songs.push(app.songs[0].clone());
+        app.move_selected_in_playlist(false).await.unwrap();
+        assert_eq!(app.song_state.selected, 1);
+        let ids: Vec<_> = mock.calls_to("createPlaylist")[0].params.iter()
+            .filter(|(k, _)| k == "songId").map(|(_, v)| v.clone()).collect();
+        assert_eq!(ids, ["s-3", "s-1", "s-1"]);
+        assert_eq!(mock.calls_to("getPlaylists").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_reorder_restores_the_order() {
+        let (mut app, mock) = app_with_mock().await;
+        app.load_playlist_songs().await.unwrap();
+        app.songs.push(app.songs[0].clone());
+        let before: Vec<_> = app.songs.iter().map(|s| s.id.clone()).collect();
+        mock.fail_with("createPlaylist", 50, "Not your playlist");
+        assert!(app.move_selected_in_playlist(false).await.is_err());
+        let after: Vec<_> = app.songs.iter().map(|s| s.id.clone()).collect();
+        assert_eq!(before, after);
+        assert_eq!(app.song_state.selected, 0);
+    }
+
+    #[tokio::test]
+    async fn playlist_songs_can_be_removed() {
+        let (mut app, mock) = app_with_mock().await;
+        app.load_playlist_songs().await.unwrap();
+        app.song_state.selected = 1;
+        app.remove_selected_from_playlist().await.unwrap();
+        assert_eq!(mock.calls_to("updatePlaylist")[0].param("songIndexToRemove"), Some("1"));
+        assert_eq!(app.songs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn deleting_a_playlist_needs_confirmation() {
+        let (mut app, mock) = app_with_mock().await;
+        app.mode = ViewMode::Playlists;
+        app.delete_selected_playlist().await.unwrap();
+        assert!(mock.calls_to("deletePlaylist").is_empty());
+        app.delete_selected_playlist().await.unwrap();
+        assert_eq!(mock.calls_to("deletePlaylist")[0].param("id"), Some("pl-1"));
+    }
+}
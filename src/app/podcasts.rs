@@ -0,0 +1,234 @@
+use anyhow::Result;
+
+use crate::api::models::{PodcastChannel, PodcastEpisode};
+use super::{bookmarks::{format_position, BookmarkCandidate}, App, InputPrompt, InputPurpose, PanelState, ViewMode};
+
+/// Episodes in the "newest" pseudo channel at the top of the list.
+const NEWEST_COUNT: u32 = 20;
+
+/// Short badge for an episode's download status.
+pub fn episode_status_label(status: &str) -> &'static str {
+    match status {
+        "completed"   => "✓",
+        "downloading" => "⏬",
+        "new"         => "🆕",
+        "error"       => "⚠",
+        "deleted"     => "🗑",
+        "skipped"     => "–",
+        _             => "?",
+    }
+}
+
+impl App {
+    /// Shift+O – podcast channels on the left, episodes in the middle.
+    pub async fn toggle_podcasts(&mut self) -> Result<()> {
+        if matches!(self.mode, ViewMode::Podcasts | ViewMode::PodcastEpisodes) {
+            self.mode = ViewMode::Artists;
+            return Ok(());
+        }
+        self.close_search();
+        self.refresh_podcasts().await?;
+        self.podcast_state = PanelState::default();
+        self.episode_state = PanelState::default();
+        self.mode          = ViewMode::Podcasts;
+        Ok(())
+    }
+
+    pub(super) async fn refresh_podcasts(&mut self) -> Result<()> {
+        self.podcasts        = self.client.get_podcasts().await?;
+        self.newest_episodes = self.client.get_newest_podcasts(NEWEST_COUNT).await?;
+        Ok(())
+    }
+
+    /// Selected channel; `None` for the "newest episodes" entry at index 0.
+    pub fn selected_channel(&self) -> Option<&PodcastChannel> {
+        self.podcast_state.selected.checked_sub(1).and_then(|i| self.podcasts.get(i))
+    }
+
+    /// Episodes shown in the middle column.
+    pub fn podcast_episodes(&self) -> &[PodcastEpisode] {
+        match self.selected_channel() {
+            Some(channel) => &channel.episode,
+            None          => &self.newest_episodes,
+        }
+    }
+
+    /// → on a channel: refresh (download states change on the server) and focus its episodes.
+    pub async fn open_podcast_channel(&mut self) -> Result<()> {
+        self.refresh_podcasts().await?;
+        self.episode_state = PanelState::default();
+        self.mode          = ViewMode::PodcastEpisodes;
+        Ok(())
+    }
+
+    /// Shift+N in the podcast view.
+    pub fn start_new_podcast(&mut self) {
+        self.input = Some(InputPrompt::new(InputPurpose::NewPodcast));
+    }
+
+    /// Subscribes to a feed URL.
+    pub async fn create_podcast_channel(&mut self, url: &str) -> Result<()> {
+        self.client.create_podcast_channel(url).await?;
+        self.refresh_podcasts().await?;
+        self.status_message = format!("🎙 Subscribed to {}", url);
+        Ok(())
+    }
+
+    /// Enter on an episode: play it if downloaded (resuming from its bookmark),
+    /// otherwise ask the server to download it.
+    pub async fn activate_episode(&mut self) -> Result<()> {
+        let Some(episode) = self.podcast_episodes().get(self.episode_state.selected).cloned() else {
+            return Ok(());
+        };
+        match (episode.status.as_str(), episode.to_song()) {
+            ("completed", Some(song)) => {
+                let position_ms = self.client.get_bookmarks().await?
+                    .into_iter()
+                    .find(|b| b.entry.id == song.id)
+                    .map(|b| b.position)
+                    .unwrap_or(0);
+                self.resume_at  = (position_ms >= 1000).then_some(position_ms / 1000);
+                self.songs      = vec![song.clone()];
+                self.song_state = PanelState::default();
+                self.is_shuffle = false;
+                self.start_playback().await?;
+                // Episodes are bookmarked whatever their length
+                self.bookmark_candidate = Some(BookmarkCandidate {
+                    id:         song.id,
+                    duration:   song.duration,
+                    bookmarked: position_ms >= 1000,
+                });
+                self.status_message = if position_ms >= 1000 {
+                    format!("🎙 {} – resuming at {}", episode.title, format_position(position_ms))
+                } else {
+                    format!("🎙 {}", episode.title)
+                };
+            }
+            ("downloading", _) => {
+                self.refresh_podcasts().await?;
+                self.status_message = format!("⏬ Still downloading: {}", episode.title);
+            }
+            _ => {
+                self.client.download_podcast_episode(&episode.id).await?;
+                self.refresh_podcasts().await?;
+                self.status_message = format!("⏬ Downloading: {}", episode.title);
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete on an episode – press twice, like deleting a playlist.
+    pub async fn delete_selected_episode(&mut self) -> Result<()> {
+        let Some(episode) = self.podcast_episodes().get(self.episode_state.selected).cloned() else {
+            return Ok(());
+        };
+        if self.pending_delete.as_deref() != Some(episode.id.as_str()) {
+            self.pending_delete = Some(episode.id.clone());
+            self.status_message = format!("Press Delete again to delete the download of '{}'", episode.title);
+            return Ok(());
+        }
+        self.pending_delete = None;
+        self.client.delete_podcast_episode(&episode.id).await?;
+        self.refresh_podcasts().await?;
+        self.status_message = format!("🗑 Deleted: {}", episode.title);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::super::tests::{app_with_config, app_with_mock};
+    use super::*;
+    use crate::api::mock::MockServer;
+
+    /// Podcast view with the first channel's episodes focused.
+    async fn first_channel() -> (App, MockServer) {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_podcasts().await.unwrap();
+        app.on_down();
+        app.open_podcast_channel().await.unwrap();
+        (app, mock)
+    }
+
+    #[tokio::test]
+    async fn newest_episodes_come_before_the_channels() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_podcasts().await.unwrap();
+        assert_eq!(app.mode, ViewMode::Podcasts);
+        assert!(app.selected_channel().is_none());
+        assert_eq!(app.podcast_episodes().len(), 1);
+
+        app.on_down();
+        app.open_podcast_channel().await.unwrap();
+        assert_eq!(app.mode, ViewMode::PodcastEpisodes);
+        assert_eq!(app.podcast_episodes().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn downloaded_episode_resumes_and_is_bookmarked_when_stopped() {
+        let (mut app, mock) = first_channel().await;
+        app.activate_episode().await.unwrap();
+        assert_eq!(app.songs[0].id, "pe-1");
+        assert!(app.status_message.contains("resuming at 20:34"), "{}", app.status_message);
+
+        app.player_status.current_time.store(1_500_000, Ordering::Relaxed);
+        app.stop_playback().await;
+        let call = &mock.calls_to("createBookmark")[0];
+        assert_eq!(call.param("id"), Some("pe-1"));
+        assert_eq!(call.param("position"), Some("1500000"));
+    }
+
+    #[tokio::test]
+    async fn short_episode_stays_bookmarkable_once_playing() {
+        let (mut app, mock) = app_with_config("[bookmarks]\nmin_minutes = 90\n").await;
+        app.toggle_podcasts().await.unwrap();
+        app.on_down();
+        app.open_podcast_channel().await.unwrap();
+        app.activate_episode().await.unwrap();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.update_now_playing().await;
+        assert!(mock.calls_to("deleteBookmark").is_empty());
+
+        app.player_status.current_time.store(1_500_000, Ordering::Relaxed);
+        app.stop_playback().await;
+        assert_eq!(mock.calls_to("createBookmark")[0].param("id"), Some("pe-1"));
+    }
+
+    #[tokio::test]
+    async fn missing_episode_is_downloaded_by_the_server() {
+        let (mut app, mock) = first_channel().await;
+        app.on_down();
+        app.activate_episode().await.unwrap();
+        assert_eq!(mock.calls_to("downloadPodcastEpisode")[0].param("id"), Some("ep-2"));
+        assert!(app.songs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_download_request_is_an_error() {
+        let (mut app, mock) = first_channel().await;
+        mock.fail_with("downloadPodcastEpisode", 50, "Not allowed");
+        app.on_down();
+        let err = app.activate_episode().await.unwrap_err();
+        assert!(err.to_string().contains("Not allowed"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn deleting_an_episode_needs_confirmation() {
+        let (mut app, mock) = first_channel().await;
+        app.on_down();
+        app.delete_selected_episode().await.unwrap();
+        assert!(mock.calls_to("deletePodcastEpisode").is_empty());
+        app.delete_selected_episode().await.unwrap();
+        assert_eq!(mock.calls_to("deletePodcastEpisode")[0].param("id"), Some("ep-2"));
+    }
+
+    #[tokio::test]
+    async fn failed_podcast_list_stays_in_the_library() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getPodcasts", 50, "Podcasts disabled");
+        assert!(app.toggle_podcasts().await.is_err());
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+}
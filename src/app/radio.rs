@@ -0,0 +1,182 @@
+use anyhow::Result;
+use rand::seq::SliceRandom;
+
+use crate::api::models::Song;
+use super::{App, PanelState, ViewMode};
+
+/// Similar songs asked for per `getSimilarSongs2` call.
+const RADIO_BATCH: u32 = 30;
+
+/// What song radio stays close to.
+pub struct RadioSeed {
+    pub artist_id: String,
+    /// Song title or artist name, for the status line and panels.
+    pub label:     String,
+}
+
+impl App {
+    /// Shift+R – endless radio seeded from the selected artist (Artists view)
+    /// or the selected / playing song. Runs on top of the jukebox machinery.
+    pub async fn start_radio(&mut self) -> Result<()> {
+        let (seed, first) = match self.mode {
+            ViewMode::Artists => match self.artists.get(self.artist_state.selected) {
+                Some(a) => (RadioSeed { artist_id: a.id.clone(), label: a.name.clone() }, None),
+                None    => return Ok(()),
+            },
+            _ => match self.selected_song() {
+                Some(song) => match song.artist_id.clone() {
+                    Some(artist_id) => (RadioSeed { artist_id, label: song.title.clone() }, Some(song.clone())),
+                    None => {
+                        self.status_message = "📻 This song has no artist to start a radio from".to_string();
+                        return Ok(());
+                    }
+                },
+                None => return Ok(()),
+            },
+        };
+
+        let mut queue: Vec<Song> = first.iter().cloned().collect();
+        for song in self.client.get_similar_songs(&seed.artist_id, RADIO_BATCH).await? {
+            if !queue.iter().any(|s| s.id == song.id) { queue.push(song); }
+        }
+        // The seed song alone is no radio
+        if queue.len() <= first.is_some() as usize {
+            self.status_message = format!("📻 No similar songs for {}", seed.label);
+            return Ok(());
+        }
+
+        self.enter_jukebox_mode();
+        let label        = seed.label.clone();
+        self.radio_seed  = Some(seed);
+        self.songs       = queue;
+        self.song_state  = PanelState::default();
+        self.mode        = ViewMode::Jukebox;
+        self.start_playback().await?;
+        self.status_message = format!("📻 Radio: {}", label);
+        Ok(())
+    }
+
+    /// Next songs for the radio queue: similar to the playing song's artist,
+    /// then to the seed. Once neither has anything new left, similar songs
+    /// are played again; random ones only when there are none at all.
+    pub(super) async fn next_radio_batch(&mut self) -> Vec<Song> {
+        let Some(seed) = &self.radio_seed else { return Vec::new(); };
+        let playing = self.now_playing
+            .and_then(|i| self.songs.get(i))
+            .and_then(|s| s.artist_id.clone())
+            .filter(|id| *id != seed.artist_id);
+        let artist_ids: Vec<String> = playing.into_iter().chain(std::iter::once(seed.artist_id.clone())).collect();
+
+        let mut pool: Vec<Song> = Vec::new();
+        for artist_id in &artist_ids {
+            let similar = match self.client.get_similar_songs(artist_id, RADIO_BATCH).await {
+                Ok(similar) => similar,
+                Err(e) => {
+                    self.status_message = format!("❌ Radio refill failed: {}", e);
+                    continue;
+                }
+            };
+            let fresh: Vec<Song> = similar.iter()
+                .filter(|s| !self.songs.iter().any(|have| have.id == s.id))
+                .cloned()
+                .collect();
+            if !fresh.is_empty() { return fresh; }
+            for song in similar {
+                if !pool.iter().any(|have| have.id == song.id) { pool.push(song); }
+            }
+        }
+
+        // Replay the similar songs rather than drift off to random ones. A full
+        // batch, so the queue stays above the jukebox's refill threshold until
+        // these have actually been played.
+        if !pool.is_empty() {
+            pool.shuffle(&mut rand::thread_rng());
+            if pool.len() > 1 && self.songs.last().is_some_and(|last| last.id == pool[0].id) {
+                pool.rotate_left(1);
+            }
+            return pool.iter().cycle().take(RADIO_BATCH as usize).cloned().collect();
+        }
+        self.status_message = "📻 No similar songs left – radio continues with random songs".to_string();
+        self.client.get_random_songs(RADIO_BATCH as u16).await.unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    async fn radio_from_first_song() -> (App, crate::api::mock::MockServer) {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.mode  = ViewMode::Songs;
+        app.start_radio().await.unwrap();
+        (app, mock)
+    }
+
+    #[tokio::test]
+    async fn radio_starts_with_the_seed_song() {
+        let (app, mock) = radio_from_first_song().await;
+        assert!(app.is_jukebox_mode);
+        assert_eq!(app.radio_seed.as_ref().unwrap().label, "Dancing Queen");
+        let ids: Vec<_> = app.songs.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["s-1", "sim-1", "sim-2", "sim-3"]);
+        assert_eq!(mock.calls_to("getSimilarSongs2")[0].param("id"), Some("ar-1"));
+    }
+
+    #[tokio::test]
+    async fn radio_repeats_similar_songs_once_none_are_new() {
+        let (mut app, mock) = radio_from_first_song().await;
+        for _ in 0..5 {
+            // Near the end of the queue, then one more tick right after the refill
+            app.player_status.current_index.store(app.songs.len() - 2, Ordering::Release);
+            app.update_now_playing().await;
+            app.jukebox_tick().await.unwrap();
+            app.jukebox_tick().await.unwrap();
+        }
+        assert!(mock.calls_to("getSimilarSongs2").len() > 5); // refilled every round
+        assert!(mock.calls_to("getRandomSongs").is_empty());
+        assert!(app.songs.iter().skip(1).all(|s| s.id.starts_with("sim-")));
+        assert!(app.songs.windows(2).all(|w| w[0].id != w[1].id));
+    }
+
+    #[tokio::test]
+    async fn radio_switching_to_random_songs_is_reported() {
+        let (mut app, mock) = radio_from_first_song().await;
+        mock.respond_with("getSimilarSongs2", serde_json::json!({"similarSongs2": {}}));
+        app.player_status.current_index.store(2, Ordering::Release);
+        app.jukebox_tick().await.unwrap();
+        assert_eq!(app.songs.len(), 4 + 30);
+        assert_eq!(mock.calls_to("getRandomSongs").len(), 1);
+        assert!(app.status_message.contains("random songs"), "{}", app.status_message);
+    }
+
+    #[tokio::test]
+    async fn single_similar_song_starts_a_radio() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.respond_with("getSimilarSongs2", serde_json::json!({"similarSongs2": {"song": [
+            {"id": "sim-9", "title": "Only one", "artistId": "ar-3", "isDir": false},
+        ]}}));
+        app.start_radio().await.unwrap();
+        assert!(app.is_jukebox_mode);
+        assert_eq!(app.songs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn stopping_ends_the_radio() {
+        let (mut app, _mock) = radio_from_first_song().await;
+        app.stop_playback().await;
+        assert!(app.radio_seed.is_none());
+    }
+
+    #[tokio::test]
+    async fn failed_similar_songs_start_no_radio() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getSimilarSongs2", 0, "Last.fm unreachable");
+        assert!(app.start_radio().await.is_err());
+        assert!(!app.is_jukebox_mode);
+        assert!(app.radio_seed.is_none());
+    }
+}
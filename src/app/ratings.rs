@@ -0,0 +1,80 @@
+use anyhow::Result;
+
+use super::{App, ViewMode};
+
+impl App {
+    /// 1–5 rate the selected album or song (in the jukebox and visualizer:
+    /// the playing song), 0 clears the rating.
+    pub async fn rate_selected(&mut self, rating: u8) -> Result<()> {
+        let rating = rating.min(5);
+        let (id, name) = match self.mode {
+            ViewMode::Albums => match self.albums.get(self.album_state.selected) {
+                Some(album) => (album.id.clone(), album.name.clone()),
+                None        => return Ok(()),
+            },
+            ViewMode::Songs | ViewMode::PlaylistSongs | ViewMode::Jukebox | ViewMode::Visualizer => match self.selected_song() {
+                Some(song) => (song.id.clone(), song.title.clone()),
+                None       => return Ok(()),
+            },
+            _ => {
+                self.status_message = "❌ Select a song or album to rate".to_string();
+                return Ok(());
+            }
+        };
+
+        self.client.set_rating(&id, rating).await?;
+        let value = (rating > 0).then_some(rating);
+        for album in self.albums.iter_mut().chain(self.current_album.as_mut()).filter(|a| a.id == id) {
+            album.user_rating = value;
+        }
+        for song in self.songs.iter_mut().filter(|s| s.id == id) {
+            song.user_rating = value;
+        }
+        self.status_message = match value {
+            Some(r) => format!("{} {}", "★".repeat(r as usize), name),
+            None    => format!("☆ Rating cleared: {}", name),
+        };
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn rating_selected_album() {
+        let (mut app, mock) = app_with_mock().await;
+        app.load_albums().await.unwrap();
+        app.rate_selected(4).await.unwrap();
+        assert_eq!(app.albums[0].user_rating, Some(4));
+        assert_eq!(mock.calls_to("setRating")[0].param("id"), Some("1"));
+    }
+
+    #[tokio::test]
+    async fn rating_selected_song_and_clearing_it() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.mode  = ViewMode::Songs;
+        app.song_state.selected = 1;
+        app.rate_selected(5).await.unwrap();
+        assert_eq!(app.songs[1].user_rating, Some(5));
+        app.rate_selected(0).await.unwrap();
+        assert_eq!(app.songs[1].user_rating, None);
+
+        let calls = mock.calls_to("setRating");
+        assert_eq!(calls[0].param("rating"), Some("5"));
+        assert_eq!(calls[1].param("rating"), Some("0"));
+    }
+
+    #[tokio::test]
+    async fn failed_rating_keeps_the_old_one() {
+        let (mut app, mock) = app_with_mock().await;
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.mode  = ViewMode::Songs;
+        mock.fail_with("setRating", 50, "Not allowed");
+        assert!(app.rate_selected(3).await.is_err());
+        assert_eq!(app.songs[0].user_rating, None);
+    }
+}
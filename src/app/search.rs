@@ -0,0 +1,220 @@
+use anyhow::{anyhow, Result};
+
+use crate::api::models::{Artist, SearchPage, SearchResult};
+use super::{album_list::PAGE_AHEAD, App, PanelState, ViewMode};
+
+/// Results fetched per type and `search3` page.
+const SEARCH_PAGE: u32 = 50;
+
+/// Where the result columns came from.
+pub enum ResultSource {
+    Search(String),
+    Favorites,
+}
+
+/// An active search or favorites list. Its artists, albums and songs are
+/// shown in the three regular panels, so drilling down works exactly like in
+/// the library.
+pub struct SearchState {
+    pub source:   ResultSource,
+    /// The library's artist list, restored when the search is closed.
+    pub(super) library_artists: Vec<Artist>,
+    library_artist_state: PanelState,
+    /// No further `search3` pages for this type – or the list has been
+    /// replaced by drilling down.
+    pub artists_done: bool,
+    pub albums_done:  bool,
+    pub songs_done:   bool,
+}
+
+impl SearchState {
+    /// Panel title for one result column, e.g. `🔍 Albums: 'abba'`.
+    pub fn label(&self, column: &str) -> String {
+        match &self.source {
+            ResultSource::Search(query) => format!("🔍 {}: '{}'", column, query),
+            ResultSource::Favorites     => format!("❤️ Starred {}", column.to_lowercase()),
+        }
+    }
+}
+
+impl App {
+    /// Enter in the search field.
+    pub async fn run_search(&mut self) -> Result<()> {
+        self.is_search_mode = false;
+        let query = self.search_query.trim().to_string();
+        if query.is_empty() { return Ok(()); }
+
+        let page = SearchPage {
+            artist_count: SEARCH_PAGE,
+            album_count:  SEARCH_PAGE,
+            song_count:   SEARCH_PAGE,
+            ..SearchPage::default()
+        };
+        let result = self.client.search(&query, &page).await
+            .map_err(|e| anyhow!("Search error: {}", e))?;
+        let full = |n: usize| n as u32 >= SEARCH_PAGE;
+        let more = (full(result.artist.len()), full(result.album.len()), full(result.song.len()));
+
+        self.status_message = if result.artist.is_empty() && result.album.is_empty() && result.song.is_empty() {
+            "🔍 No results found".to_string()
+        } else {
+            self.search_history.push(query.clone());
+            format!("✅ Found {} artists, {} albums, {} songs",
+                result.artist.len(), result.album.len(), result.song.len())
+        };
+        self.show_results(ResultSource::Search(query), result, more);
+        Ok(())
+    }
+
+    /// Swaps `result` into the three panels. `more` tells which columns
+    /// (artists, albums, songs) may have further pages.
+    pub(super) fn show_results(&mut self, source: ResultSource, result: SearchResult, more: (bool, bool, bool)) {
+        let (library_artists, library_artist_state) = match self.search.take() {
+            Some(previous) => (previous.library_artists, previous.library_artist_state),
+            None           => (std::mem::take(&mut self.artists), self.artist_state),
+        };
+        self.search = Some(SearchState {
+            source,
+            library_artists,
+            library_artist_state,
+            artists_done: !more.0,
+            albums_done:  !more.1,
+            songs_done:   !more.2,
+        });
+        // Jump to the most specific section that has hits
+        self.mode = if !result.song.is_empty() { ViewMode::Songs }
+            else if !result.album.is_empty() { ViewMode::Albums }
+            else { ViewMode::Artists };
+        self.artists         = result.artist;
+        self.albums          = result.album;
+        self.songs           = result.song;
+        self.current_artist  = None;
+        self.current_album   = None;
+        self.album_list      = None;
+        self.current_genre   = None;
+        self.artist_state    = PanelState::default();
+        self.album_state     = PanelState::default();
+        self.song_state      = PanelState::default();
+        self.is_jukebox_mode = false;
+        self.is_shuffle      = false;
+    }
+
+    /// Esc after a search – back to the library's artists.
+    pub fn close_search(&mut self) {
+        let Some(search) = self.search.take() else { return; };
+        self.artists        = search.library_artists;
+        self.artist_state   = search.library_artist_state;
+        self.current_artist = None;
+        self.current_album  = None;
+        self.albums.clear();
+        self.album_state    = PanelState::default();
+        if matches!(self.mode, ViewMode::Artists | ViewMode::Albums) {
+            self.mode = ViewMode::Artists;
+        }
+    }
+
+    /// Fetches the next `search3` page of the focused section once the
+    /// selection gets close to its end.
+    pub async fn load_more_search_results(&mut self) -> Result<()> {
+        let Some(search) = &self.search else { return Ok(()); };
+        // Favorites come in one piece
+        let ResultSource::Search(query) = &search.source else { return Ok(()); };
+        let query = query.clone();
+        let near_end = |state: &PanelState, len: usize| state.selected + PAGE_AHEAD >= len;
+        let mut page = SearchPage::default();
+        match self.mode {
+            ViewMode::Artists if !search.artists_done && near_end(&self.artist_state, self.artists.len()) => {
+                page.artist_count  = SEARCH_PAGE;
+                page.artist_offset = self.artists.len() as u32;
+            }
+            ViewMode::Albums if !search.albums_done && near_end(&self.album_state, self.albums.len()) => {
+                page.album_count  = SEARCH_PAGE;
+                page.album_offset = self.albums.len() as u32;
+            }
+            ViewMode::Songs if !search.songs_done && near_end(&self.song_state, self.songs.len()) => {
+                page.song_count  = SEARCH_PAGE;
+                page.song_offset = self.songs.len() as u32;
+            }
+            _ => return Ok(()),
+        }
+
+        let result = self.client.search(&query, &page).await?;
+        let Some(search) = self.search.as_mut() else { return Ok(()); };
+        match self.mode {
+            ViewMode::Artists => {
+                search.artists_done = (result.artist.len() as u32) < SEARCH_PAGE;
+                self.artists.extend(result.artist);
+            }
+            ViewMode::Albums => {
+                search.albums_done = (result.album.len() as u32) < SEARCH_PAGE;
+                self.albums.extend(result.album);
+            }
+            _ => {
+                search.songs_done = (result.song.len() as u32) < SEARCH_PAGE;
+                self.songs.extend(result.song);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn search_shows_all_sections() {
+        let (mut app, mock) = app_with_mock().await;
+        app.search_query = "dancing".to_string();
+        app.run_search().await.unwrap();
+        assert_eq!((app.artists.len(), app.albums.len(), app.songs.len()), (1, 1, 50));
+        assert_eq!(app.mode, ViewMode::Songs);
+        assert_eq!(app.search_history, ["dancing"]);
+        assert_eq!(mock.calls_to("search3")[0].param("query"), Some("dancing"));
+    }
+
+    #[tokio::test]
+    async fn song_hits_are_paged_until_a_short_page() {
+        let (mut app, mock) = app_with_mock().await;
+        app.search_query = "dancing".to_string();
+        app.run_search().await.unwrap();
+
+        app.song_state.selected = 49;
+        app.load_more().await.unwrap();
+        assert_eq!(app.songs.len(), 75);
+        let page = &mock.calls_to("search3")[1];
+        assert_eq!(page.param("songOffset"), Some("50"));
+        assert_eq!(page.param("artistCount"), Some("0"));
+        // Last page was short – no further requests
+        app.load_more().await.unwrap();
+        assert_eq!(mock.calls_to("search3").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn artist_hits_drill_down_and_closing_restores_the_library() {
+        let (mut app, mock) = app_with_mock().await;
+        app.search_query = "dancing".to_string();
+        app.run_search().await.unwrap();
+        app.mode = ViewMode::Artists;
+        app.load_albums().await.unwrap();
+        assert_eq!(mock.calls_to("getArtist")[0].param("id"), Some("ar-1"));
+
+        app.close_search();
+        assert!(app.search.is_none());
+        assert_eq!(app.artists.len(), 2);
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+
+    #[tokio::test]
+    async fn failed_search_keeps_the_library() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("search3", 0, "Index not ready");
+        app.search_query = "dancing".to_string();
+        let err = app.run_search().await.unwrap_err();
+        assert!(err.to_string().starts_with("Search error"), "{}", err);
+        assert!(app.search.is_none());
+        assert!(app.search_history.is_empty());
+        assert_eq!(app.artists.len(), 2);
+    }
+}
@@ -0,0 +1,132 @@
+use anyhow::Result;
+
+use crate::api::models::Share;
+use super::{App, PanelState, ViewMode};
+
+impl App {
+    /// Shift+X – public link for the selected song, album or playlist,
+    /// shown in a popup and copied to the clipboard.
+    pub async fn share_selected(&mut self) -> Result<()> {
+        let target = match self.mode {
+            ViewMode::Songs | ViewMode::PlaylistSongs | ViewMode::Jukebox => self.songs
+                .get(self.song_state.selected)
+                .map(|s| (s.id.clone(), format!("{} – {}", s.title, s.artist.as_deref().unwrap_or("Unknown")))),
+            ViewMode::Albums => self.albums
+                .get(self.album_state.selected)
+                .map(|a| (a.id.clone(), format!("{} – {}", a.name, a.artist))),
+            ViewMode::Playlists => self.playlists
+                .get(self.playlist_state.selected)
+                .map(|pl| (pl.id.clone(), pl.name.clone())),
+            _ => None,
+        };
+        let Some((id, description)) = target else {
+            self.status_message = "🔗 Select a song, album or playlist to share".to_string();
+            return Ok(());
+        };
+        let share = self.client.create_share(&id, &description).await?;
+        self.status_message = format!("🔗 Shared {}", description);
+        self.show_share(share);
+        Ok(())
+    }
+
+    /// Shift+V – the user's share links.
+    pub async fn toggle_shares(&mut self) -> Result<()> {
+        if self.mode == ViewMode::Shares {
+            self.mode = ViewMode::Artists;
+            return Ok(());
+        }
+        self.close_search();
+        self.shares      = self.client.get_shares().await?;
+        self.share_state = PanelState::default();
+        self.mode        = ViewMode::Shares;
+        if self.shares.is_empty() {
+            self.status_message = "🔗 No shares yet – Shift+X shares the selection".to_string();
+        }
+        Ok(())
+    }
+
+    /// Enter on a share: show and copy its link again.
+    pub fn show_selected_share(&mut self) {
+        if let Some(share) = self.shares.get(self.share_state.selected).cloned() {
+            self.show_share(share);
+        }
+    }
+
+    fn show_share(&mut self, share: Share) {
+        self.clipboard   = Some(share.url.clone());
+        self.share_popup = Some(share);
+    }
+
+    /// Delete on a share – press twice.
+    pub async fn delete_selected_share(&mut self) -> Result<()> {
+        let Some(share) = self.shares.get(self.share_state.selected).cloned() else { return Ok(()); };
+        if self.pending_delete.as_deref() != Some(share.id.as_str()) {
+            self.pending_delete = Some(share.id.clone());
+            self.status_message = format!("Press Delete again to delete the share {}", share.url);
+            return Ok(());
+        }
+        self.pending_delete = None;
+        self.client.delete_share(&share.id).await?;
+        self.shares = self.client.get_shares().await?;
+        self.share_state.selected = self.share_state.selected.min(self.shares.len().saturating_sub(1));
+        self.status_message = format!("🗑 Share deleted: {}", share.url);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn artists_cannot_be_shared() {
+        let (mut app, mock) = app_with_mock().await;
+        app.share_selected().await.unwrap();
+        assert!(mock.calls_to("createShare").is_empty());
+        assert!(app.share_popup.is_none());
+    }
+
+    #[tokio::test]
+    async fn shared_album_link_is_shown_and_copied() {
+        let (mut app, mock) = app_with_mock().await;
+        app.load_albums().await.unwrap();
+        let album_id = app.albums[0].id.clone();
+        app.share_selected().await.unwrap();
+        assert_eq!(mock.calls_to("createShare")[0].param("id"), Some(album_id.as_str()));
+        let url = format!("https://mock.example/share/{}", album_id);
+        assert_eq!(app.share_popup.as_ref().map(|s| s.url.as_str()), Some(url.as_str()));
+        assert_eq!(app.clipboard.take(), Some(url));
+    }
+
+    #[tokio::test]
+    async fn failed_share_copies_nothing() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("createShare", 50, "Sharing is disabled");
+        app.load_albums().await.unwrap();
+        let err = app.share_selected().await.unwrap_err();
+        assert!(err.to_string().contains("Sharing is disabled"), "{}", err);
+        assert!(app.share_popup.is_none());
+        assert!(app.clipboard.is_none());
+    }
+
+    #[tokio::test]
+    async fn listed_share_is_copied_again() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_shares().await.unwrap();
+        assert_eq!(app.mode, ViewMode::Shares);
+        assert_eq!(app.shares[0].visit_count, 3);
+        app.show_selected_share();
+        assert_eq!(app.clipboard.as_deref(), Some("https://mock.example/share/sh-1"));
+    }
+
+    #[tokio::test]
+    async fn deleting_a_share_needs_confirmation() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_shares().await.unwrap();
+        app.delete_selected_share().await.unwrap();
+        assert!(mock.calls_to("deleteShare").is_empty());
+        app.delete_selected_share().await.unwrap();
+        assert_eq!(mock.calls_to("deleteShare")[0].param("id"), Some("sh-1"));
+    }
+}
@@ -0,0 +1,81 @@
+use anyhow::Result;
+
+use super::{App, PanelState, ViewMode};
+
+impl App {
+    /// Shift+T – the server's internet radio stations in the left column.
+    pub async fn toggle_stations(&mut self) -> Result<()> {
+        if self.mode == ViewMode::Stations {
+            self.mode = ViewMode::Artists;
+            return Ok(());
+        }
+        self.close_search();
+        self.stations      = self.client.get_internet_radio_stations().await?;
+        self.station_state = PanelState::default();
+        self.mode          = ViewMode::Stations;
+        if self.stations.is_empty() {
+            self.status_message = "📡 No internet radio stations on the server".to_string();
+        }
+        Ok(())
+    }
+
+    /// Enter on a station: stream it through mpv. Stations have no duration,
+    /// so nothing is scrobbled and the progress bar only counts up.
+    pub async fn play_station(&mut self) -> Result<()> {
+        let Some(station) = self.stations.get(self.station_state.selected).cloned() else {
+            return Ok(());
+        };
+        self.is_jukebox_mode = false;
+        self.radio_seed      = None;
+        self.is_shuffle      = false;
+        if self.spawn_player(&format!("{}\n", station.stream_url), 1, 0, false)? {
+            self.now_playing     = None;
+            self.status_message  = format!("📡 Playing: {}", station.name);
+            self.current_station = Some(station);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::super::tests::app_with_mock;
+    use super::*;
+
+    #[tokio::test]
+    async fn stations_are_listed_and_toggled_off() {
+        let (mut app, _mock) = app_with_mock().await;
+        app.toggle_stations().await.unwrap();
+        assert_eq!(app.mode, ViewMode::Stations);
+        assert_eq!(app.stations.len(), 2);
+        assert_eq!(app.stations[0].home_page_url.as_deref(), Some("https://www.radioswissjazz.ch"));
+
+        app.toggle_stations().await.unwrap();
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+
+    #[tokio::test]
+    async fn stations_are_never_scrobbled() {
+        let (mut app, mock) = app_with_mock().await;
+        app.toggle_stations().await.unwrap();
+        // mpv reports position 0 of the one-entry station playlist
+        app.songs = app.client.get_album_songs("1").await.unwrap();
+        app.current_station = app.stations.first().cloned();
+        app.player_status.current_index.store(0, Ordering::Release);
+        app.player_status.current_time.store(60_000, Ordering::Relaxed);
+        app.update_now_playing().await;
+        app.check_and_scrobble().await;
+        assert_eq!(app.now_playing, None);
+        assert!(mock.calls_to("scrobble").is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_station_list_stays_in_the_library() {
+        let (mut app, mock) = app_with_mock().await;
+        mock.fail_with("getInternetRadioStations", 50, "Not allowed");
+        assert!(app.toggle_stations().await.is_err());
+        assert_eq!(app.mode, ViewMode::Artists);
+    }
+}
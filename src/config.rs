@@ -1,20 +1,122 @@
 use anyhow::Result;
 use directories::ProjectDirs;
 use serde::Deserialize;
-use std::path::Path;
+use std::{collections::BTreeMap, path::{Path, PathBuf}};
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Config {
-    pub server: ServerConfig,
+    pub server:     ServerConfig,
+    #[serde(default)]
+    pub play_queue: PlayQueueConfig,
+    #[serde(default)]
+    pub lyrics:     LyricsConfig,
+    #[serde(default)]
+    pub bookmarks:  BookmarkConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct ServerConfig {
     pub url:      String,
     pub username: String,
+    #[serde(default)]
     pub password: String,
+    /// Shell command whose first line of output is the password (e.g. `pass show navidrome`).
+    pub password_command: Option<String>,
+    /// Environment variable holding the password.
+    pub password_env: Option<String>,
+    #[serde(default)]
+    pub auth:     AuthConfig,
+    /// Per-request timeout in seconds.
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// How often a failed request is retried: connect errors always, timeouts
+    /// and 5xx only for read-only calls.
+    #[serde(default = "default_retries")]
+    pub retries: u32,
 }
 
+/// `[server.auth]` – how TerminalDrome authenticates against the server.
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct AuthConfig {
+    #[serde(default)]
+    pub mode:    AuthMode,
+    /// OpenSubsonic API key, required for `mode = "apikey"`.
+    pub api_key: Option<String>,
+    /// Extra HTTP headers sent with every API request and stream, e.g. for a
+    /// reverse proxy in front of the server (`Authorization = "Basic …"`).
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthMode {
+    /// Salted MD5 token (`t` + `s`), Subsonic API ≥ 1.13.0.
+    #[default]
+    Token,
+    /// Legacy hex-encoded password (`p=enc:…`), needed for LDAP/external auth.
+    Password,
+    /// OpenSubsonic `apiKey`.
+    #[serde(alias = "api_key")]
+    ApiKey,
+}
+
+/// `[play_queue]` – shares the queue with other Subsonic clients
+/// (`savePlayQueue` / `getPlayQueue`).
+#[derive(Debug, Deserialize, Clone)]
+pub struct PlayQueueConfig {
+    /// Save queue, current song and position on track change and on quit.
+    #[serde(default = "default_true")]
+    pub save:            bool,
+    /// Continue the server's saved queue at startup.
+    #[serde(default)]
+    pub resume_on_start: bool,
+}
+
+impl Default for PlayQueueConfig {
+    fn default() -> Self {
+        Self { save: true, resume_on_start: false }
+    }
+}
+
+/// `[bookmarks]` – remember the position in long tracks (audiobooks, mixes, …).
+#[derive(Debug, Deserialize, Clone)]
+pub struct BookmarkConfig {
+    /// Bookmark tracks at least this long when they are stopped or skipped mid-way; 0 turns it off.
+    #[serde(default = "default_bookmark_min_minutes")]
+    pub min_minutes: u64,
+}
+
+impl Default for BookmarkConfig {
+    fn default() -> Self {
+        Self { min_minutes: default_bookmark_min_minutes() }
+    }
+}
+
+/// `[lyrics]`
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct LyricsConfig {
+    /// Local `.lrc` files, used when the server has no synced lyrics. Either
+    /// mirroring the library (`Artist/Album/01 Title.lrc`) or flat
+    /// (`Artist - Title.lrc`). `~/` is expanded.
+    pub dir: Option<String>,
+}
+
+impl LyricsConfig {
+    pub fn dir(&self) -> Option<PathBuf> {
+        let dir = self.dir.as_deref()?;
+        match (dir.strip_prefix("~/"), std::env::var_os("HOME")) {
+            (Some(rest), Some(home)) => Some(PathBuf::from(home).join(rest)),
+            _                        => Some(PathBuf::from(dir)),
+        }
+    }
+}
+
+fn default_true() -> bool { true }
+fn default_bookmark_min_minutes() -> u64 { 20 }
+fn default_timeout_secs() -> u64 { 15 }
+fn default_retries() -> u32 { 2 }
+
 pub fn read_config() -> Result<Config> {
     let config_name = "config.toml";
 
@@ -46,5 +148,106 @@ pub fn read_config() -> Result<Config> {
 
 fn parse_config(path: &Path) -> Result<Config> {
     let content = std::fs::read_to_string(path)?;
-    Ok(toml::from_str(&content)?)
+    let mut config: Config = toml::from_str(&content)?;
+    config.server.resolve_password()?;
+    config.server.auth.validate()?;
+    Ok(config)
+}
+
+impl AuthConfig {
+    /// Catches an auth mode that can't work before the first request does.
+    pub fn validate(&self) -> Result<()> {
+        if self.mode == AuthMode::ApiKey && self.api_key.as_deref().is_none_or(|k| k.trim().is_empty()) {
+            anyhow::bail!("[server.auth] mode = \"apikey\" needs an api_key");
+        }
+        Ok(())
+    }
+}
+
+impl ServerConfig {
+    /// Replaces `password` with the value from `password_env` or
+    /// `password_command`, if one of them is set (env wins).
+    pub fn resolve_password(&mut self) -> Result<()> {
+        if let Some(var) = self.password_env.as_deref() {
+            self.password = std::env::var(var)
+                .map_err(|_| anyhow::anyhow!("password_env: environment variable {} is not set", var))?;
+        } else if let Some(cmd) = self.password_command.as_deref() {
+            let output = std::process::Command::new("sh").arg("-c").arg(cmd).output()?;
+            if !output.status.success() {
+                anyhow::bail!("password_command failed ({}): {}", output.status, String::from_utf8_lossy(&output.stderr).trim());
+            }
+            // `pass` and friends may print more (URL, notes) after the first line
+            let stdout = String::from_utf8(output.stdout)?;
+            self.password = stdout.lines().next().unwrap_or_default().to_string();
+            if self.password.is_empty() {
+                anyhow::bail!("password_command printed no password");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(extra: &str) -> ServerConfig {
+        let cfg: Config = toml::from_str(&format!(
+            "[server]\nurl = \"http://localhost\"\nusername = \"me\"\npassword = \"plain\"\n{}", extra,
+        )).unwrap();
+        cfg.server
+    }
+
+    #[test]
+    fn plain_password_is_kept() {
+        let mut s = server("");
+        s.resolve_password().unwrap();
+        assert_eq!(s.password, "plain");
+    }
+
+    #[test]
+    fn password_command_output_is_used() {
+        let mut s = server("password_command = \"printf 'from-cmd\\\\n'\"");
+        s.resolve_password().unwrap();
+        assert_eq!(s.password, "from-cmd");
+    }
+
+    #[test]
+    fn only_the_first_line_of_password_command_is_used() {
+        let mut s = server("password_command = \"printf 'hunter2\\r\\nURL: https://example.org\\n'\"");
+        s.resolve_password().unwrap();
+        assert_eq!(s.password, "hunter2");
+    }
+
+    #[test]
+    fn empty_password_command_output_is_an_error() {
+        let mut s = server("password_command = \"printf '\\nsecond line\\n'\"");
+        assert!(s.resolve_password().is_err());
+        let mut s = server("password_command = \"true\"");
+        assert!(s.resolve_password().is_err());
+    }
+
+    #[test]
+    fn failing_password_command_is_an_error() {
+        let mut s = server("password_command = \"exit 3\"");
+        assert!(s.resolve_password().is_err());
+    }
+
+    #[test]
+    fn api_key_mode_needs_a_key() {
+        let s = server("[server.auth]\nmode = \"api_key\"");
+        assert!(s.auth.validate().is_err());
+        let s = server("[server.auth]\nmode = \"apikey\"\napi_key = \"  \"");
+        assert!(s.auth.validate().is_err());
+        let s = server("[server.auth]\nmode = \"apikey\"\napi_key = \"k-123\"");
+        assert!(s.auth.validate().is_ok());
+    }
+
+    #[test]
+    fn password_env_wins() {
+        std::env::set_var("TERMINALDROME_TEST_PASSWORD", "from-env");
+        let mut s = server("password_env = \"TERMINALDROME_TEST_PASSWORD\"\npassword_command = \"echo nope\"");
+        s.resolve_password().unwrap();
+        assert_eq!(s.password, "from-env");
+    }
 }
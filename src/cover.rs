@@ -1,19 +1,24 @@
 
-use std::{collections::HashMap, io::Cursor, sync::Mutex};
+use std::{collections::{HashMap, HashSet}, io::Cursor, sync::Mutex};
 use anyhow::Result;
 use image::{imageops::{colorops::grayscale, FilterType}, io::Reader as ImageReader};
 
-use crate::api::{build_auth_query, models::Album};
-use crate::config::Config;
+use crate::api::{models::Album, SubsonicClient};
 
 lazy_static! {
     pub static ref COVER_CACHE: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+    /// Cover ids already fetched by `cached_cover_or_fetch` – in flight or failed.
+    static ref COVER_REQUESTED: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
 }
 
-pub async fn get_ascii_cover(album: Option<&Album>, config: &Config) -> String {
+pub async fn get_ascii_cover(album: Option<&Album>, client: &SubsonicClient) -> String {
     let Some(album)    = album else { return default_cover_art(); };
     let Some(cover_id) = &album.cover_art else { return default_cover_art(); };
+    get_ascii_cover_by_id(cover_id, client).await
+}
 
+/// Same as `get_ascii_cover`, for anything that carries a `coverArt` id (songs, playlists, …).
+pub async fn get_ascii_cover_by_id(cover_id: &str, client: &SubsonicClient) -> String {
     {
         let cache = COVER_CACHE.lock().unwrap();
         if let Some(cached) = cache.get(cover_id) {
@@ -21,10 +26,10 @@ pub async fn get_ascii_cover(album: Option<&Album>, config: &Config) -> String {
         }
     }
 
-    match fetch_cover_art(cover_id, config).await {
+    match client.get_cover_art(cover_id).await {
         Ok(img_data) => {
             let ascii = image_to_ascii(&img_data, 30).unwrap_or_else(|_| default_cover_art());
-            COVER_CACHE.lock().unwrap().insert(cover_id.clone(), ascii.clone());
+            COVER_CACHE.lock().unwrap().insert(cover_id.to_string(), ascii.clone());
             ascii
         }
         Err(e) => {
@@ -34,13 +39,19 @@ pub async fn get_ascii_cover(album: Option<&Album>, config: &Config) -> String {
     }
 }
 
-async fn fetch_cover_art(cover_id: &str, config: &Config) -> Result<Vec<u8>> {
-    let mut params = build_auth_query(config);
-    params.push(("id".to_string(), cover_id.to_string()));
-    let response = reqwest::Client::new()
-        .get(format!("{}/rest/getCoverArt", config.server.url))
-        .query(&params).send().await?;
-    Ok(response.bytes().await?.to_vec())
+/// The cached cover for `cover_id`, or `None` while it loads. For render code,
+/// which runs every frame: each id is fetched once in the background, and one
+/// that failed is not asked for again.
+pub fn cached_cover_or_fetch(cover_id: &str, client: &SubsonicClient) -> Option<String> {
+    if let Some(cover) = COVER_CACHE.lock().unwrap().get(cover_id) {
+        return Some(cover.clone());
+    }
+    if COVER_REQUESTED.lock().unwrap().insert(cover_id.to_string()) {
+        let client   = client.clone();
+        let cover_id = cover_id.to_string();
+        tokio::spawn(async move { let _ = get_ascii_cover_by_id(&cover_id, &client).await; });
+    }
+    None
 }
 
 pub fn image_to_ascii(img_data: &[u8], width: u32) -> Result<String> {
@@ -0,0 +1,215 @@
+//! Lyrics of the playing song – from the server (`getLyricsBySongId`,
+//! `getLyrics`) or a local `.lrc` file – and the LRC parser behind them.
+
+use std::path::{Path, PathBuf};
+use anyhow::Result;
+
+use crate::api::{models::{Song, StructuredLyrics}, SubsonicClient};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LyricLine {
+    /// Milliseconds; `None` for unsynced lyrics.
+    pub start_ms: Option<u64>,
+    pub text:     String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LyricsSource {
+    Server,
+    LocalFile,
+}
+
+#[derive(Debug, Clone)]
+pub struct Lyrics {
+    pub lines:     Vec<LyricLine>,
+    pub synced:    bool,
+    /// Milliseconds added to the playback position; positive shows lines earlier.
+    pub offset_ms: i64,
+    pub source:    LyricsSource,
+}
+
+impl Lyrics {
+    pub fn plain(text: &str, source: LyricsSource) -> Self {
+        let lines = text.lines().map(|l| LyricLine { start_ms: None, text: l.trim_end().to_string() }).collect();
+        Self { lines, synced: false, offset_ms: 0, source }
+    }
+
+    fn from_structured(lyrics: StructuredLyrics) -> Self {
+        let lines = lyrics.line.into_iter()
+            .map(|l| LyricLine { start_ms: l.start.filter(|_| lyrics.synced), text: l.value })
+            .collect();
+        Self { lines, synced: lyrics.synced, offset_ms: lyrics.offset, source: LyricsSource::Server }
+    }
+
+    /// Parses LRC (`[mm:ss.xx]text`, several stamps per line, `[offset:±ms]`).
+    /// Text without any timestamps comes back as unsynced lyrics.
+    pub fn parse_lrc(text: &str, source: LyricsSource) -> Self {
+        let mut lines     = Vec::new();
+        let mut offset_ms = 0;
+        for raw in text.lines() {
+            let mut rest   = raw.trim();
+            let mut stamps = Vec::new();
+            while let Some(tag) = rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+                let (tag, after) = tag;
+                if let Some(ms) = parse_timestamp(tag) {
+                    stamps.push(ms);
+                } else if let Some(value) = tag.strip_prefix("offset:") {
+                    offset_ms = value.trim().parse().unwrap_or(0);
+                }
+                // Other tags ([ar:], [ti:], …) are metadata
+                rest = after;
+            }
+            for start in stamps {
+                lines.push(LyricLine { start_ms: Some(start), text: rest.trim().to_string() });
+            }
+        }
+        if lines.is_empty() {
+            return Self::plain(text, source);
+        }
+        lines.sort_by_key(|l| l.start_ms);
+        Self { lines, synced: true, offset_ms, source }
+    }
+
+    /// Index of the line being sung at `position_ms`.
+    pub fn current_line(&self, position_ms: u64) -> Option<usize> {
+        if !self.synced { return None; }
+        let position = (position_ms as i64 + self.offset_ms).max(0) as u64;
+        self.lines.iter().rposition(|l| l.start_ms.is_some_and(|s| s <= position))
+    }
+}
+
+/// `mm:ss`, `mm:ss.xx` or `mm:ss.xxx` → milliseconds.
+fn parse_timestamp(tag: &str) -> Option<u64> {
+    let (min, sec) = tag.split_once(':')?;
+    let min: u64 = min.trim().parse().ok()?;
+    let (sec, frac) = sec.split_once(['.', ':']).unwrap_or((sec, "0"));
+    let sec: u64 = sec.trim().parse().ok()?;
+    let frac_ms = match frac.len() {
+        1 => frac.parse::<u64>().ok()? * 100,
+        2 => frac.parse::<u64>().ok()? * 10,
+        _ => frac.get(..3)?.parse::<u64>().ok()?,
+    };
+    Some(min * 60_000 + sec * 1000 + frac_ms)
+}
+
+/// `dir/<path of the song>.lrc`, or `dir/Artist - Title.lrc`.
+pub fn local_lrc_path(dir: &Path, song: &Song) -> Option<PathBuf> {
+    let mirrored = song.path.as_deref().map(|p| dir.join(p).with_extension("lrc"));
+    let flat     = song.artist.as_deref()
+        .map(|artist| dir.join(format!("{} - {}.lrc", artist, song.title).replace('/', "_")));
+    mirrored.into_iter().chain(flat).find(|p| p.is_file())
+}
+
+/// Best lyrics for `song`: synced ones from the server, then a local `.lrc`
+/// file, then the server's plain text. A failing `getLyricsBySongId` counts
+/// as no server lyrics; its error is only returned when nothing else turns up.
+pub async fn fetch_lyrics(
+    client:     &SubsonicClient,
+    song:       &Song,
+    structured: bool,
+    local_dir:  Option<&Path>,
+) -> Result<Option<Lyrics>> {
+    let (mut server, server_error): (Vec<StructuredLyrics>, _) = if structured {
+        match client.get_lyrics_by_song_id(&song.id).await {
+            Ok(lyrics) => (lyrics, None),
+            Err(e)     => (Vec::new(), Some(e)),
+        }
+    } else {
+        (Vec::new(), None)
+    };
+    if let Some(i) = server.iter().position(|l| l.synced && !l.line.is_empty()) {
+        return Ok(Some(Lyrics::from_structured(server.swap_remove(i))));
+    }
+
+    if let Some(path) = local_dir.and_then(|dir| local_lrc_path(dir, song)) {
+        let text = tokio::fs::read_to_string(&path).await?;
+        return Ok(Some(Lyrics::parse_lrc(&text, LyricsSource::LocalFile)));
+    }
+
+    if let Some(unsynced) = server.into_iter().find(|l| !l.line.is_empty()) {
+        return Ok(Some(Lyrics::from_structured(unsynced)));
+    }
+    let plain = match song.artist.as_deref() {
+        Some(artist) => client.get_lyrics(artist, &song.title).await?,
+        None         => None,
+    };
+    match (plain, server_error) {
+        (Some(text), _) => Ok(Some(Lyrics::plain(&text, LyricsSource::Server))),
+        (None, Some(e)) => Err(e),
+        (None, None)    => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mock::MockServer;
+
+    #[test]
+    fn lrc_lines_are_timed_and_sorted() {
+        let lrc = "[ti:Song]\n[offset:+500]\n[00:12.50][01:02.00]Chorus\n[00:01.5]Intro\nno stamp\n";
+        let lyrics = Lyrics::parse_lrc(lrc, LyricsSource::LocalFile);
+        assert!(lyrics.synced);
+        assert_eq!(lyrics.offset_ms, 500);
+        let starts: Vec<_> = lyrics.lines.iter().map(|l| l.start_ms.unwrap()).collect();
+        assert_eq!(starts, [1_500, 12_500, 62_000]);
+        assert_eq!(lyrics.lines[1].text, "Chorus");
+
+        assert_eq!(lyrics.current_line(0), None);
+        assert_eq!(lyrics.current_line(1_000), Some(0)); // offset pulls it forward
+        assert_eq!(lyrics.current_line(30_000), Some(1));
+    }
+
+    #[test]
+    fn text_without_stamps_is_unsynced() {
+        let lyrics = Lyrics::parse_lrc("just\nwords", LyricsSource::LocalFile);
+        assert!(!lyrics.synced);
+        assert_eq!(lyrics.lines.len(), 2);
+        assert_eq!(lyrics.current_line(5_000), None);
+    }
+
+    #[tokio::test]
+    async fn server_synced_lyrics_win_then_local_then_plain() {
+        let mock = MockServer::start().await;
+        let client = mock.client();
+        let mut song: Song = serde_json::from_value(crate::api::mock::song_json("s-1", "Dancing Queen", "1")).unwrap();
+
+        let lyrics = fetch_lyrics(&client, &song, true, None).await.unwrap().unwrap();
+        assert!(lyrics.synced);
+        assert_eq!(lyrics.current_line(5_000), Some(1));
+
+        // No server lyrics for s-9 → local file
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Mock Artist - Waterloo.lrc"), "[00:03.00]My my").unwrap();
+        song.id    = "s-9".to_string();
+        song.title = "Waterloo".to_string();
+        let lyrics = fetch_lyrics(&client, &song, true, Some(dir.path())).await.unwrap().unwrap();
+        assert_eq!(lyrics.source, LyricsSource::LocalFile);
+        assert_eq!(lyrics.lines[0].text, "My my");
+
+        // Neither → getLyrics
+        song.title = "Money, Money, Money".to_string();
+        let lyrics = fetch_lyrics(&client, &song, false, Some(dir.path())).await.unwrap().unwrap();
+        assert!(!lyrics.synced);
+        assert_eq!(lyrics.lines.len(), 2);
+        assert_eq!(mock.calls_to("getLyricsBySongId").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn local_lrc_is_used_when_the_server_fails() {
+        let mock = MockServer::start().await;
+        mock.fail_with("getLyricsBySongId", 0, "Lyrics plugin crashed");
+        let mut song: Song = serde_json::from_value(crate::api::mock::song_json("s-1", "Dancing Queen", "1")).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Mock Artist - Dancing Queen.lrc"), "[00:03.00]You can dance").unwrap();
+
+        let lyrics = fetch_lyrics(&mock.client(), &song, true, Some(dir.path())).await.unwrap().unwrap();
+        assert_eq!(lyrics.source, LyricsSource::LocalFile);
+        assert_eq!(lyrics.lines[0].text, "You can dance");
+
+        // Nothing local either → the server's error
+        song.title = "Waterloo".to_string();
+        let err = fetch_lyrics(&mock.client(), &song, true, Some(dir.path())).await.unwrap_err();
+        assert!(err.to_string().contains("Lyrics plugin crashed"), "{}", err);
+    }
+}
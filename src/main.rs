@@ -24,13 +24,14 @@ use std::sync::atomic::Ordering;
 mod config;
 mod api;
 mod app;
+mod clipboard;
 mod cover;
+mod lyrics;
 mod ui;
 mod visual;
 
 use app::{App, PanelState, ViewMode};
 use app::normalize_for_search;
-use api::endpoints::search_songs;
 use ui::ui;
 
 #[tokio::main]
@@ -102,8 +103,15 @@ async fn main() -> Result<(), Box<dyn Error>> {
         if last_ui_update.elapsed() > effective_refresh {
             app.update_now_playing().await;
             app.check_and_scrobble().await;
+            app.now_playing_tick().await;
+            app.chat_tick().await;
+            app.play_queue_tick().await;
+            app.lyrics_tick().await;
+            let result = app.folder_walk_tick().await;
+            report_error(&mut app, result);
             if app.is_jukebox_mode {
-                app.jukebox_tick().await?;
+                let result = app.jukebox_tick().await;
+                report_error(&mut app, result);
             }
             if app.mode == ViewMode::Visualizer {
                 app.visualizer.tick();
@@ -112,12 +120,56 @@ async fn main() -> Result<(), Box<dyn Error>> {
             last_ui_update = Instant::now();
         }
 
+        if let Some(text) = app.clipboard.take() {
+            if let Err(e) = clipboard::copy(&text) {
+                app.status_message = format!("❌ Could not copy to the clipboard: {}", e);
+            }
+        }
+
         if event::poll(Duration::from_millis(50))? {
             match event::read()? {
                 Event::Key(key) if key.kind == KeyEventKind::Press => {
                     if app.is_help_mode {
                         app.is_help_mode = false;
+                    } else if app.share_popup.is_some() {
+                        app.share_popup = None;
+                    } else if let Some(prompt) = app.input.as_mut() {
+                        match key.code {
+                            KeyCode::Esc       => { app.input = None; }
+                            KeyCode::Backspace => { prompt.text.pop(); }
+                            KeyCode::Char(c)   => { prompt.text.push(c); }
+                            KeyCode::Enter     => {
+                                let result = app.submit_input().await;
+                                report_error(&mut app, result);
+                            }
+                            _ => {}
+                        }
+                    } else if app.folder_picker.is_some() {
+                        match key.code {
+                            KeyCode::Esc       => { app.folder_picker = None; }
+                            KeyCode::Up        => app.folder_picker_up(),
+                            KeyCode::Down      => app.folder_picker_down(),
+                            KeyCode::Char(' ') => app.toggle_picked_folder(),
+                            KeyCode::Enter     => {
+                                let result = app.apply_folder_picker().await;
+                                report_error(&mut app, result);
+                            }
+                            _ => {}
+                        }
+                    } else if app.playlist_picker.is_some() {
+                        match key.code {
+                            KeyCode::Esc      => { app.playlist_picker = None; }
+                            KeyCode::Up       => app.picker_up(),
+                            KeyCode::Down     => app.picker_down(),
+                            KeyCode::Tab      => app.cycle_picker_source(),
+                            KeyCode::Enter    => {
+                                let result = app.confirm_playlist_picker().await;
+                                report_error(&mut app, result);
+                            }
+                            _ => {}
+                        }
                     } else {
+                        if key.code != KeyCode::Delete { app.pending_delete = None; }
                         match key.code {
                             KeyCode::Char('H') if key.modifiers.contains(KeyModifiers::SHIFT) => {
                                 app.is_help_mode = true;
@@ -125,22 +177,144 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
                             // NEU: Shift+L für Like - wichtig: vor der bestehenden 'H' Logik!
                             KeyCode::Char('L') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
-                                let _ = app.like_current_song().await;
+                                let result = app.toggle_star().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('F') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_favorites().await;
+                                report_error(&mut app, result);
                             }
 
                             KeyCode::Char('Q') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                                let result = app.save_play_queue_on_quit().await;
+                                report_error(&mut app, result);
                                 app.stop_playback().await;
                                 app.should_quit = true;
                             }
+                            KeyCode::Char('A') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                app.toggle_album_browser();
+                            }
+                            KeyCode::Char('U') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
+                                let result = app.resume_play_queue().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('I') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_artist_info().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('Y') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
+                                app.toggle_lyrics();
+                            }
+                            KeyCode::PageUp   if app.show_lyrics => app.scroll_lyrics(false),
+                            KeyCode::PageDown if app.show_lyrics => app.scroll_lyrics(true),
+                            KeyCode::Char('P') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
+                                app.open_playlist_picker();
+                            }
+                            KeyCode::Char('N') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && app.mode == ViewMode::Playlists =>
+                            {
+                                app.start_new_playlist();
+                            }
+                            KeyCode::Char('N') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && app.mode == ViewMode::Podcasts =>
+                            {
+                                app.start_new_podcast();
+                            }
+                            KeyCode::F(2) if app.mode == ViewMode::Playlists => app.start_rename_playlist(),
+                            KeyCode::Delete if !app.is_search_mode => {
+                                let result = match app.mode {
+                                    ViewMode::Playlists     => app.delete_selected_playlist().await,
+                                    ViewMode::PlaylistSongs => app.remove_selected_from_playlist().await,
+                                    ViewMode::PodcastEpisodes => app.delete_selected_episode().await,
+                                    ViewMode::Bookmarks     => app.delete_selected_bookmark().await,
+                                    ViewMode::Shares        => app.delete_selected_share().await,
+                                    _ => Ok(()),
+                                };
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('G') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_genres().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('R') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
+                                let result = app.start_radio().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('T') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_stations().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('O') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_podcasts().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('B') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_bookmarks().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('D') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_folders().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('W') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_now_playing().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('C') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
+                                app.toggle_chat();
+                            }
+                            KeyCode::Char('K') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
+                                app.start_chat_message();
+                            }
+                            KeyCode::Char('Z') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
+                                let result = app.post_playing_song_to_chat().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('M') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.open_folder_picker().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('X') if key.modifiers.contains(KeyModifiers::SHIFT) && !app.is_search_mode => {
+                                let result = app.share_selected().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Char('V') if key.modifiers.contains(KeyModifiers::SHIFT)
+                                && !app.is_search_mode && !app.is_jukebox_mode =>
+                            {
+                                let result = app.toggle_shares().await;
+                                report_error(&mut app, result);
+                            }
                             KeyCode::Char('J') if key.modifiers.contains(KeyModifiers::SHIFT) => {
-                                app.start_jukebox().await?;
+                                let result = app.start_jukebox().await;
+                                report_error(&mut app, result);
                             }
                             KeyCode::Char('S') if key.modifiers.contains(KeyModifiers::SHIFT)
                                 && !app.is_search_mode =>
                             {
                                 match app.mode {
                                     ViewMode::Songs | ViewMode::PlaylistSongs | ViewMode::Jukebox => {
-                                        app.shuffle_and_restart().await?;
+                                        let result = app.shuffle_and_restart().await;
+                                        report_error(&mut app, result);
                                     }
                                     _ => {}
                                 }
@@ -157,7 +331,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                                     if let Some(fifo) = app.visualizer.fifo_path().map(|p| p.to_path_buf()) {
                                         if let Some(idx) = app.now_playing {
                                             if let Some(song) = app.songs.get(idx) {
-                                                let url     = api::build_stream_url(&song.id, &app.config);
+                                                let url     = api::build_stream_url(&song.id, &app.client);
                                                 let pos_sec = (app.player_status.current_time.load(Ordering::Relaxed) / 1000) as u64;
                                                 app.visualizer.start_ffmpeg_feeder(&url, &fifo, pos_sec);
                                             }
@@ -174,16 +348,17 @@ async fn main() -> Result<(), Box<dyn Error>> {
                             KeyCode::Char('m') if !app.is_search_mode => { app.toggle_mute().await; }
                             KeyCode::Char('n') if !app.is_search_mode => app.next_track().await,
                             KeyCode::Char('p') if !app.is_search_mode => app.previous_track().await,
-                            KeyCode::Tab if !app.is_search_mode => {
-                                if !app.is_jukebox_mode {
-                                    match app.mode {
-                                        ViewMode::Playlists | ViewMode::PlaylistSongs => { app.mode = ViewMode::Artists; }
-                                        _ => {
-                                            app.mode = ViewMode::Playlists;
-                                            app.current_album = None;
-                                            app.albums.clear();
-                                            app.album_state = PanelState::default();
-                                        }
+                            KeyCode::Tab if !app.is_search_mode && !app.is_jukebox_mode => {
+                                match app.mode {
+                                    ViewMode::Playlists | ViewMode::PlaylistSongs => { app.mode = ViewMode::Artists; }
+                                    _ => {
+                                        app.mode = ViewMode::Playlists;
+                                        app.close_search();
+                                        app.album_list = None;
+                                        app.current_genre = None;
+                                        app.current_album = None;
+                                        app.albums.clear();
+                                        app.album_state = PanelState::default();
                                     }
                                 }
                             }
@@ -217,8 +392,62 @@ async fn main() -> Result<(), Box<dyn Error>> {
                                             app.adjust_playlist_scroll();
                                         }
                                     }
+                                    ViewMode::Genres => {
+                                        if let Some(pos) = app.genres.iter().position(|g| normalize_for_search(&g.value).starts_with(&sc)) {
+                                            app.genre_state.selected = pos;
+                                            app.adjust_scroll();
+                                        }
+                                    }
+                                    ViewMode::Stations => {
+                                        if let Some(pos) = app.stations.iter().position(|st| normalize_for_search(&st.name).starts_with(&sc)) {
+                                            app.station_state.selected = pos;
+                                            app.adjust_scroll();
+                                        }
+                                    }
+                                    ViewMode::Podcasts => {
+                                        if let Some(pos) = app.podcasts.iter().position(|c| normalize_for_search(c.display_title()).starts_with(&sc)) {
+                                            app.podcast_state.selected = pos + 1;
+                                            app.episode_state = PanelState::default();
+                                            app.adjust_scroll();
+                                        }
+                                    }
+                                    ViewMode::PodcastEpisodes => {
+                                        if let Some(pos) = app.podcast_episodes().iter().position(|e| normalize_for_search(&e.title).starts_with(&sc)) {
+                                            app.episode_state.selected = pos;
+                                            app.adjust_scroll();
+                                        }
+                                    }
+                                    ViewMode::NowPlaying => {
+                                        if let Some(pos) = app.now_playing_entries.iter().position(|e| normalize_for_search(&e.song.title).starts_with(&sc)) {
+                                            app.now_playing_state.selected = pos;
+                                            app.adjust_scroll();
+                                        }
+                                    }
+                                    ViewMode::Folders => {
+                                        if let Some(pos) = app.folder_entries().iter().position(|e| normalize_for_search(&e.title).starts_with(&sc)) {
+                                            app.folder_state.selected = pos;
+                                            app.adjust_scroll();
+                                        }
+                                    }
+                                    ViewMode::Shares => {
+                                        if let Some(pos) = app.shares.iter().position(|sh| normalize_for_search(sh.description.as_deref().unwrap_or(&sh.url)).starts_with(&sc)) {
+                                            app.share_state.selected = pos;
+                                            app.adjust_scroll();
+                                        }
+                                    }
+                                    ViewMode::Bookmarks => {
+                                        if let Some(pos) = app.bookmarks.iter().position(|b| normalize_for_search(&b.entry.title).starts_with(&sc)) {
+                                            app.bookmark_state.selected = pos;
+                                            app.adjust_scroll();
+                                        }
+                                    }
+                                    ViewMode::AlbumList | ViewMode::ArtistInfo => {}
                                 }
                             }
+                            KeyCode::Char(c @ '0'..='5') if !app.is_search_mode => {
+                                let result = app.rate_selected(c as u8 - b'0').await;
+                                report_error(&mut app, result);
+                            }
                             KeyCode::Char('/') => {
                                 app.is_search_mode = true;
                                 app.search_query.clear();
@@ -229,7 +458,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                                     app.visualizer.detach_audio();
                                     let _ = terminal.clear();
                                 } else {
-                                    app.is_search_mode = false;
+                                    if app.is_search_mode { app.is_search_mode = false; } else { app.close_search(); }
                                     if app.is_jukebox_mode {
                                         app.stop_playback().await;
                                         app.mode = ViewMode::Artists;
@@ -237,52 +466,55 @@ async fn main() -> Result<(), Box<dyn Error>> {
                                 }
                             }
                             KeyCode::Enter if app.is_search_mode => {
-                                match search_songs(&app.search_query, &app.config).await {
-                                    Ok(results) => {
-                                        app.search_results = results.clone();
-                                        app.songs = results;
-                                        if app.songs.is_empty() {
-                                            app.status_message = "🔍 No results found".to_string();
-                                        } else {
-                                            app.search_history.push(app.search_query.clone());
-                                            app.status_message = format!("✅ Found {} songs", app.songs.len());
-                                        }
-                                        // Einheitliches Reset für alle Fälle
-                                        app.current_artist = None;
-                                        app.current_album = None;
-                                        app.artist_state = PanelState::default();
-                                        app.album_state = PanelState::default();
-                                        app.song_state = PanelState::default();
-                                        app.mode = ViewMode::Songs;
-                                        app.is_search_mode = false;
-                                        app.is_jukebox_mode = false;
-                                        app.is_shuffle = false;
-                                        app.adjust_scroll();
-                                    }
-                                    Err(e) => {
-                                        app.status_message = format!("❌ Search error: {}", e);
-                                        app.songs.clear();
-                                        app.search_results.clear();
-                                        app.is_search_mode = false; // zurück zum normalen Modus
-                                        app.mode = ViewMode::Songs; // leere Liste anzeigen
-                                        // Panel-Zustände zurücksetzen (optional)
-                                        app.song_state = PanelState::default();
-                                    }
-                                }
+                                let result = app.run_search().await;
+                                report_error(&mut app, result);
                             }
                             KeyCode::Char(c) if app.is_search_mode => { app.search_query.push(c); }
                             KeyCode::Backspace if app.is_search_mode => { app.search_query.pop(); }
+                            KeyCode::Up | KeyCode::Down
+                                if key.modifiers.contains(KeyModifiers::SHIFT) && app.mode == ViewMode::PlaylistSongs =>
+                            {
+                                let result = app.move_selected_in_playlist(key.code == KeyCode::Up).await;
+                                report_error(&mut app, result);
+                            }
                             KeyCode::Up   => app.on_up(),
-                            KeyCode::Down => app.on_down(),
-                            KeyCode::Left => { if !app.is_jukebox_mode { app.mode = app.mode.previous(); } }
-                            KeyCode::Right | KeyCode::Enter => match app.mode {
-                                ViewMode::Artists       => app.load_albums().await?,
-                                ViewMode::Albums        => app.load_songs().await?,
-                                ViewMode::Songs         => app.start_playback().await?,
-                                ViewMode::Playlists     => app.load_playlist_songs().await?,
-                                ViewMode::PlaylistSongs => app.start_playback().await?,
-                                ViewMode::Jukebox | ViewMode::Visualizer => {}
-                            },
+                            KeyCode::Down => {
+                                app.on_down();
+                                let result = app.load_more().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Left if !app.is_jukebox_mode => { app.go_back(); }
+                            // Genres: → opens the albums, Enter plays the whole genre
+                            KeyCode::Right if app.mode == ViewMode::Genres => {
+                                let result = app.open_genre_albums().await;
+                                report_error(&mut app, result);
+                            }
+                            // Folders: → descends, Enter plays the folder recursively
+                            KeyCode::Right if app.mode == ViewMode::Folders => {
+                                let result = app.open_selected_folder().await;
+                                report_error(&mut app, result);
+                            }
+                            KeyCode::Right | KeyCode::Enter => {
+                                let result = match app.mode {
+                                    ViewMode::Artists       => app.load_albums().await,
+                                    ViewMode::Albums        => app.load_songs().await,
+                                    ViewMode::Songs         => app.start_playback().await,
+                                    ViewMode::Playlists     => app.load_playlist_songs().await,
+                                    ViewMode::PlaylistSongs => app.start_playback().await,
+                                    ViewMode::AlbumList     => app.select_album_list_preset().await,
+                                    ViewMode::Genres        => app.play_genre().await,
+                                    ViewMode::ArtistInfo    => app.activate_artist_info_entry().await,
+                                    ViewMode::Stations      => app.play_station().await,
+                                    ViewMode::Podcasts      => app.open_podcast_channel().await,
+                                    ViewMode::PodcastEpisodes => app.activate_episode().await,
+                                    ViewMode::Bookmarks     => app.resume_bookmark().await,
+                                    ViewMode::Shares        => { app.show_selected_share(); Ok(()) }
+                                    ViewMode::Folders       => app.play_selected_folder_entry().await,
+                                    ViewMode::NowPlaying    => app.queue_now_playing_entry().await,
+                                    ViewMode::Jukebox | ViewMode::Visualizer => Ok(()),
+                                };
+                                report_error(&mut app, result);
+                            }
                             KeyCode::Char(' ') => {
                                 app.stop_playback().await;
                                 app.mode = ViewMode::Artists;
@@ -306,3 +538,11 @@ async fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Shows a failed action (e.g. a Subsonic error) in the status line instead of
+/// tearing down the whole TUI.
+fn report_error(app: &mut App, result: Result<()>) {
+    if let Err(e) = result {
+        app.status_message = format!("❌ {}", e);
+    }
+}
+
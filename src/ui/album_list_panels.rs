@@ -0,0 +1,35 @@
+use ratatui::{
+    layout::Rect,
+    prelude::Frame,
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::api::models::AlbumListType;
+use crate::app::{AlbumListPreset, App, InputPurpose, ViewMode, ALBUM_LIST_PRESETS};
+
+pub fn render_album_list_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let border = if matches!(app.mode, ViewMode::AlbumList) { Color::Cyan } else { Color::LightCyan };
+    let items: Vec<ListItem> = ALBUM_LIST_PRESETS
+        .iter()
+        .enumerate()
+        .map(|(i, (label, preset))| {
+            let is_sel    = app.album_list_state.selected == i;
+            let is_active = match (preset, &app.album_list) {
+                (AlbumListPreset::List(l), Some(cur))                          => l == cur,
+                (AlbumListPreset::Ask(InputPurpose::YearRange), Some(AlbumListType::ByYear { .. })) => true,
+                (AlbumListPreset::Ask(InputPurpose::Genre), Some(AlbumListType::ByGenre(_)))        => true,
+                _ => false,
+            };
+            let style = if is_active { Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD) }
+                else if is_sel { Style::default().fg(Color::Blue) }
+                else { Style::default().fg(Color::Gray) };
+            ListItem::new(*label).style(style)
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::default().title(" Browse Albums ").borders(Borders::ALL).border_style(Style::default().fg(border))),
+        area,
+    );
+}
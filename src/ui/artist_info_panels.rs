@@ -0,0 +1,92 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    prelude::{Alignment, Frame, Line, Span},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{App, ArtistInfoView};
+use crate::cover::{cached_cover_or_fetch, default_cover_art};
+
+/// Center column: artist image and biography.
+pub fn render_artist_info_panel(frame: &mut Frame, app: &App, view: &ArtistInfoView, area: Rect) {
+    let chunks = Layout::vertical([Constraint::Length(12), Constraint::Min(3)]).split(area);
+
+    let image = cached_cover_or_fetch(view.cover_id(), &app.client).unwrap_or_else(default_cover_art);
+
+    let lines: Vec<&str> = image.lines().collect();
+    let total_lines      = lines.len().max(1);
+    let colored_ascii: Vec<Line> = lines.into_iter().enumerate().map(|(y, line)| {
+        let g     = y as f32 / total_lines as f32;
+        let color = Color::Rgb((255.0 * (1.0 - g)) as u8, 128, (255.0 * g) as u8);
+        Line::from(Span::styled(line, Style::default().fg(color)))
+    }).collect();
+    frame.render_widget(
+        Paragraph::new(colored_ascii)
+            .block(Block::default().title(format!(" {} ", view.artist.name)).borders(Borders::ALL).border_style(Style::default().fg(Color::Magenta)))
+            .alignment(Alignment::Left),
+        chunks[0],
+    );
+
+    let biography = if view.biography.is_empty() { "No biography available." } else { view.biography.as_str() };
+    frame.render_widget(
+        Paragraph::new(biography)
+            .style(Style::default().fg(Color::Gray))
+            .wrap(Wrap { trim: true })
+            .block(Block::default().title(" Biography ").borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan))),
+        chunks[1],
+    );
+}
+
+/// Right column: similar artists, then top songs – one selection across both.
+pub fn render_artist_info_list(frame: &mut Frame, app: &App, view: &ArtistInfoView, area: Rect) {
+    let style_for = |abs: usize| if app.artist_info_state.selected == abs {
+        Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+    let header = |text: &str| ListItem::new(text.to_string()).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let mut entries: Vec<ListItem> = Vec::with_capacity(view.entries() + 2);
+    entries.push(header("👥 Similar artists"));
+    if view.similar.is_empty() {
+        entries.push(ListItem::new("  –").style(Style::default().fg(Color::DarkGray)));
+    }
+    for (i, artist) in view.similar.iter().enumerate() {
+        entries.push(ListItem::new(format!("  {}", artist.name)).style(style_for(i)));
+    }
+    entries.push(header("🔝 Top songs"));
+    if view.top_songs.is_empty() {
+        entries.push(ListItem::new("  –").style(Style::default().fg(Color::DarkGray)));
+    }
+    for (i, song) in view.top_songs.iter().enumerate() {
+        let abs = view.similar.len() + i;
+        entries.push(ListItem::new(format!("  {:>2}. {}", i + 1, song.title)).style(style_for(abs)));
+    }
+
+    // `scroll` and `selected` count entries; headers and "–" placeholders are rows too
+    let similar_rows = 1 + view.similar.len().max(1);
+    let row_of = |abs: usize| if abs < view.similar.len() { 1 + abs } else { similar_rows + 1 + abs - view.similar.len() };
+    let visible  = (area.height as usize).saturating_sub(2);
+    let scroll   = app.artist_info_state.scroll;
+    let selected = row_of(app.artist_info_state.selected);
+    // A section's first entry brings its header along
+    let mut offset = match scroll {
+        0                            => 0,
+        s if s == view.similar.len() => row_of(s) - 1,
+        s                            => row_of(s),
+    };
+    if selected >= offset + visible {
+        offset = selected + 1 - visible;
+    }
+    let items: Vec<ListItem> = entries.into_iter()
+        .skip(offset)
+        .take(visible)
+        .collect();
+    frame.render_widget(
+        List::new(items).block(
+            Block::default().title(" Enter: open / play ").borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)),
+        ),
+        area,
+    );
+}
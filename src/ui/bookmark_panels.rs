@@ -0,0 +1,82 @@
+use ratatui::{
+    layout::Rect,
+    prelude::{Alignment, Frame, Line, Span},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{format_position, App, ViewMode};
+
+/// Left column: bookmarked tracks with their saved position, newest first.
+pub fn render_bookmarks_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let title  = format!(" 🔖 Bookmarks ({}) ", app.bookmarks.len());
+    let border = if matches!(app.mode, ViewMode::Bookmarks) { Color::Cyan } else { Color::LightCyan };
+
+    let items: Vec<ListItem> = app.bookmarks
+        .iter()
+        .enumerate()
+        .skip(app.bookmark_state.scroll)
+        .take((area.height as usize).saturating_sub(2))
+        .map(|(i, bookmark)| {
+            let style = if app.bookmark_state.selected == i {
+                Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(bookmark.entry.title.clone(), style),
+                Span::styled(
+                    format!("  {}/{}", format_position(bookmark.position), format_position(bookmark.entry.duration * 1000)),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border))),
+        area,
+    );
+}
+
+/// Center column: the selected bookmark's track, position and comment.
+pub fn render_bookmark_details_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let label = Style::default().fg(Color::Cyan);
+    let mut lines = vec![Line::from("")];
+    if let Some(bookmark) = app.bookmarks.get(app.bookmark_state.selected) {
+        let song = &bookmark.entry;
+        lines.push(Line::from(Span::styled(format!("  {}", song.title), Style::default().fg(Color::White).add_modifier(Modifier::BOLD))));
+        lines.push(Line::from(""));
+        if let Some(artist) = &song.artist {
+            lines.push(Line::from(vec![Span::styled("  Artist:   ", label), Span::raw(artist.clone())]));
+        }
+        if let Some(album) = &song.album {
+            lines.push(Line::from(vec![Span::styled("  Album:    ", label), Span::raw(album.clone())]));
+        }
+        lines.push(Line::from(vec![
+            Span::styled("  Position: ", label),
+            Span::styled(
+                format!("{} of {}", format_position(bookmark.position), format_position(song.duration * 1000)),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]));
+        if let Some(changed) = bookmark.changed.as_deref().and_then(|c| c.get(..10)) {
+            lines.push(Line::from(vec![Span::styled("  Saved:    ", label), Span::raw(changed.to_string())]));
+        }
+        if let Some(comment) = bookmark.comment.as_deref().filter(|c| !c.is_empty()) {
+            lines.push(Line::from(vec![Span::styled("  Comment:  ", label), Span::raw(comment.to_string())]));
+        }
+    } else {
+        lines.push(Line::from(Span::styled("  No bookmarks yet", Style::default().fg(Color::Gray))));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("  Enter – Resume   Delete – Remove   Shift+B – Back", Style::default().fg(Color::DarkGray))));
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().title(" Bookmark ").borders(Borders::ALL).border_style(Style::default().fg(Color::Magenta)))
+            .alignment(Alignment::Left),
+        area,
+    );
+}
@@ -0,0 +1,45 @@
+use ratatui::{
+    layout::Rect,
+    prelude::{Frame, Line, Span},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::app::App;
+
+/// Chat over the right column, newest message at the bottom.
+pub fn render_chat_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let block  = Block::default()
+        .title(" 💬 Chat – Shift+K write, Shift+Z post song ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta));
+    let width  = (area.width as usize).saturating_sub(2).max(1);
+    let height = (area.height as usize).saturating_sub(2);
+    let me     = &app.client.config().server.username;
+
+    // Walk back from the newest message until the wrapped lines fill the panel
+    let mut used  = 0;
+    let mut first = app.chat_messages.len();
+    for message in app.chat_messages.iter().rev() {
+        let chars = message.username.chars().count() + 2 + message.message.chars().count();
+        let rows  = chars.div_ceil(width).max(1);
+        if used + rows > height { break; }
+        used  += rows;
+        first -= 1;
+    }
+
+    let lines: Vec<Line> = if app.chat_messages.is_empty() {
+        vec![Line::styled("No messages yet", Style::default().fg(Color::DarkGray))]
+    } else {
+        app.chat_messages[first..].iter().map(|m| {
+            let name = if m.username == *me { Color::Cyan } else { Color::Yellow };
+            Line::from(vec![
+                Span::styled(format!("{}: ", m.username), Style::default().fg(name)),
+                Span::styled(m.message.clone(), Style::default().fg(Color::Gray)),
+            ])
+        }).collect()
+    };
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }).block(block), area);
+}
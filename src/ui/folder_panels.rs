@@ -0,0 +1,75 @@
+use ratatui::{
+    layout::Rect,
+    prelude::{Alignment, Frame, Line, Span},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{App, ViewMode};
+
+/// Left column: sub-folders and tracks of the shown folder.
+pub fn render_folders_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let title  = format!(" 📁 {} ", app.folder_breadcrumb());
+    let border = if matches!(app.mode, ViewMode::Folders) { Color::Cyan } else { Color::LightCyan };
+
+    let items: Vec<ListItem> = app.folder_entries()
+        .iter()
+        .enumerate()
+        .skip(app.folder_state.scroll)
+        .take((area.height as usize).saturating_sub(2))
+        .map(|(i, entry)| {
+            let style = if app.folder_state.selected == i {
+                Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)
+            } else if entry.is_dir {
+                Style::default().fg(Color::Gray)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            let icon = if entry.is_dir { "📁" } else { "♪" };
+            ListItem::new(format!("{} {}", icon, entry.title)).style(style)
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border))),
+        area,
+    );
+}
+
+/// Center column: what Enter / → do with the selected entry.
+pub fn render_folder_details_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let label   = Style::default().fg(Color::Cyan);
+    let entries = app.folder_entries();
+    let folders = entries.iter().filter(|e| e.is_dir).count();
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![Span::styled("  Folder:   ", label), Span::raw(app.folder_breadcrumb())]),
+        Line::from(vec![
+            Span::styled("  Contains: ", label),
+            Span::raw(format!("{} folders, {} tracks", folders, entries.len() - folders)),
+        ]),
+        Line::from(""),
+    ];
+    if let Some(entry) = entries.get(app.folder_state.selected) {
+        lines.push(Line::from(Span::styled(format!("  {}", entry.title), Style::default().fg(Color::White).add_modifier(Modifier::BOLD))));
+        if let Some(artist) = &entry.artist {
+            lines.push(Line::from(vec![Span::styled("  Artist:   ", label), Span::raw(artist.clone())]));
+        }
+        if let Some(path) = &entry.path {
+            lines.push(Line::from(vec![Span::styled("  File:     ", label), Span::raw(path.clone())]));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  → Open folder   Enter – Play (folders recursively)   ← Up   Shift+D – Back",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().title(" Folders ").borders(Borders::ALL).border_style(Style::default().fg(Color::Magenta)))
+            .alignment(Alignment::Left),
+        area,
+    );
+}
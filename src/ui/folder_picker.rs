@@ -0,0 +1,47 @@
+use ratatui::{
+    layout::Rect,
+    prelude::Frame,
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, List, ListItem},
+};
+
+use crate::app::App;
+
+/// Shift+M overlay: libraries with check boxes.
+pub fn render_folder_picker(frame: &mut Frame, app: &App) {
+    let Some(picker) = &app.folder_picker else { return; };
+    let sz     = frame.size();
+    let height = (picker.folders.len() as u16 + 2).min(sz.height.saturating_sub(4)).max(3);
+    let width  = (sz.width / 2).max(30).min(sz.width);
+    let area   = Rect {
+        x: sz.width.saturating_sub(width) / 2,
+        y: sz.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    let visible = (height as usize).saturating_sub(2);
+    let scroll  = (picker.selected + 1).saturating_sub(visible);
+    let items: Vec<ListItem> = picker.folders
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible)
+        .map(|(i, folder)| {
+            let check = if picker.is_chosen(folder) { "[x]" } else { "[ ]" };
+            let style = if i == picker.selected { Style::default().fg(Color::Blue) } else { Style::default().fg(Color::Gray) };
+            ListItem::new(format!("{} {}", check, folder.name)).style(style)
+        })
+        .collect();
+
+    let title = if picker.single {
+        " Libraries – Space: pick one, Enter: apply (none = all) "
+    } else {
+        " Libraries – Space: toggle, Enter: apply (none = all) "
+    };
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow))),
+        area,
+    );
+}
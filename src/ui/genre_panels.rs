@@ -0,0 +1,40 @@
+use ratatui::{
+    layout::Rect,
+    prelude::{Frame, Line, Span},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use crate::app::{App, ViewMode};
+
+pub fn render_genres_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let title  = format!(" Genres ({}) ", app.genres.len());
+    let border = if matches!(app.mode, ViewMode::Genres) { Color::Cyan } else { Color::LightCyan };
+
+    let items: Vec<ListItem> = app.genres
+        .iter()
+        .skip(app.genre_state.scroll)
+        .take((area.height as usize).saturating_sub(2))
+        .enumerate()
+        .map(|(i, genre)| {
+            let abs       = i + app.genre_state.scroll;
+            let is_sel    = app.genre_state.selected == abs;
+            let is_active = app.current_genre.as_deref() == Some(genre.value.as_str());
+            let style = if is_active { Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD) }
+                else if is_sel { Style::default().fg(Color::Blue) }
+                else { Style::default().fg(Color::Gray) };
+            ListItem::new(Line::from(vec![
+                Span::styled(genre.value.clone(), style),
+                Span::styled(
+                    format!("  {} albums · {} songs", genre.album_count, genre.song_count),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border))),
+        area,
+    );
+}
@@ -5,8 +5,12 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
-pub fn render_help(frame: &mut Frame) {
-    let help_text = vec![
+use crate::app::App;
+
+pub fn render_help(frame: &mut Frame, app: &App) {
+    let mut extensions: Vec<&str> = app.capabilities.extensions.keys().map(|k| k.as_str()).collect();
+    extensions.sort_unstable();
+    let mut help_text = vec![
         Line::from(" TerminalDrome – Keyboard Shortcuts ").style(Style::default().fg(Color::Yellow)),
         Line::from(""),
         Line::from("▶ Navigation:"),
@@ -14,6 +18,18 @@ pub fn render_help(frame: &mut Frame) {
         Line::from("  ←/→      - Switch views"),
         Line::from("  Enter    - Confirm selection"),
         Line::from("  Tab      - Toggle Playlists / Artists"),
+        Line::from("  Shift+A  - Album browser (newest, most played, by year/genre, …)"),
+        Line::from("  Shift+G  - Genres (→ albums, Enter plays the genre)"),
+        Line::from("  Shift+I  - Artist info: biography, similar artists, top songs"),
+        Line::from("  Shift+T  - Internet radio stations (Enter plays)"),
+        Line::from("  Shift+O  - Podcasts (Enter downloads / plays, Delete removes, Shift+N subscribes)"),
+        Line::from("  Shift+B  - Bookmarks (Enter resumes, Delete removes)"),
+        Line::from("  Shift+D  - Folders (→ opens, Enter plays recursively, ← up)"),
+        Line::from("  Shift+W  - Now playing on the server (Enter queues the track)"),
+        Line::from("  Shift+C  - Chat (Shift+K writes, Shift+Z posts the playing song)"),
+        Line::from("  Shift+M  - Libraries: limit browsing, search and Jukebox to music folders"),
+        Line::from("  Shift+X  - Share the selected song / album / playlist (link is copied)"),
+        Line::from("  Shift+V  - Share links (Enter copies, Delete removes)"),
         Line::from(""),
         Line::from("▶ Playback:"),
         Line::from("  Space    - Stop"),
@@ -23,22 +39,38 @@ pub fn render_help(frame: &mut Frame) {
         Line::from("  -        - Volume down"),
         Line::from("  m        - Toggle mute"),
         Line::from("  Shift+S  - Shuffle current playlist/album & restart"),
+        Line::from("  Shift+U  - Resume play queue saved on the server"),
         Line::from(""),
         Line::from("▶ Jukebox / Party Mode:"),
         Line::from("  Shift+J  - Start Jukebox (shuffles entire library)"),
-        Line::from("  ESC      - Stop Jukebox & return to Artists"),
+        Line::from("  Shift+R  - Song radio from the selected artist / song"),
+        Line::from("  ESC      - Stop Jukebox / Radio & return to Artists"),
+        Line::from(""),
+        Line::from("▶ Playlists:"),
+        Line::from("  Shift+P  - Add song / artist / album / queue to a playlist (Tab: source)"),
+        Line::from("  Shift+N  - New playlist       F2 - Rename"),
+        Line::from("  Delete   - Delete playlist (press twice) / remove song"),
+        Line::from("  Shift+↑↓ - Move song within the playlist"),
         Line::from(""),
         Line::from("▶ Other:"),
-        Line::from("  Shift+L  - Like current song"),
+        Line::from("  Shift+L  - Like / unlike selected artist, album or song"),
+        Line::from("  Shift+F  - Favorites (starred artists, albums, songs)"),
+        Line::from("  1-5 / 0  - Rate selected song or album / clear rating"),
         Line::from("  Shift+E  - Visualizer"),
-        Line::from("  /        - Search"),
+        Line::from("  Shift+Y  - Lyrics of the playing song (PgUp/PgDn scrolls)"),
+        Line::from("  /        - Search (artists, albums, songs; Esc closes)"),
         Line::from("  A-Z      - Quick jump in lists"),
         Line::from("  Shift+Q  - Quit"),
         Line::from("  Shift+H  - This help screen"),
+        Line::from(""),
+        Line::from(format!("▶ Server: {}", app.capabilities.summary())).style(Style::default().fg(Color::DarkGray)),
     ];
+    if !extensions.is_empty() {
+        help_text.push(Line::from(format!("  Extensions: {}", extensions.join(", "))).style(Style::default().fg(Color::DarkGray)));
+    }
 
     let sz       = frame.size();
-    let height   = (29u16).min(sz.height.saturating_sub(2));
+    let height   = (help_text.len() as u16 + 2).min(sz.height.saturating_sub(2));
     let width    = (sz.width / 2).min(sz.width);
     let x        = sz.width.saturating_sub(width) / 2;
     let y        = sz.height.saturating_sub(height) / 2;
@@ -6,8 +6,13 @@ use ratatui::{
 };
 use std::sync::atomic::Ordering;
 use crate::app::App;
+use super::panels::song_details;
 
-pub fn render_jukebox_left_panel(frame: &mut Frame, _app: &App, area: Rect) {
+pub fn render_jukebox_left_panel(frame: &mut Frame, app: &App, area: Rect) {
+    if let Some(seed) = &app.radio_seed {
+        render_radio_left_panel(frame, &seed.label, area);
+        return;
+    }
     let lines = vec![
         Line::from(""),
         Line::from(Span::styled("  🎉 Party / Jukebox Mode", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))),
@@ -30,17 +35,42 @@ pub fn render_jukebox_left_panel(frame: &mut Frame, _app: &App, area: Rect) {
     );
 }
 
+fn render_radio_left_panel(frame: &mut Frame, seed: &str, area: Rect) {
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled("  📻 Song Radio", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(Span::styled("  Songs similar to", Style::default().fg(Color::White))),
+        Line::from(Span::styled(format!("  {}", seed), Style::default().fg(Color::White).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(Span::styled("  More similar songs are", Style::default().fg(Color::DarkGray))),
+        Line::from(Span::styled("  loaded as the queue drains.", Style::default().fg(Color::DarkGray))),
+        Line::from(""),
+        Line::from(Span::styled("  ESC  – Radio End", Style::default().fg(Color::Yellow))),
+        Line::from(Span::styled("  n/p  – Next/Previous", Style::default().fg(Color::Yellow))),
+        Line::from(Span::styled("  Spc  – Stop", Style::default().fg(Color::Yellow))),
+    ];
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::default().title(" 📻 Radio ").borders(Borders::ALL).border_style(Style::default().fg(Color::Green)))
+            .alignment(Alignment::Left),
+        area,
+    );
+}
+
 pub fn render_jukebox_center_panel(frame: &mut Frame, app: &App, area: Rect) {
     let queued    = app.songs.len();
     let current   = app.player_status.current_index.load(Ordering::Acquire);
     let remaining = if current != usize::MAX { queued.saturating_sub(current) } else { queued };
     let now_artist = app.now_playing.and_then(|i| app.songs.get(i)).and_then(|s| s.artist.as_deref()).unwrap_or("–");
     let now_album  = app.now_playing.and_then(|i| app.songs.get(i)).and_then(|s| s.album.as_deref()).unwrap_or("–");
+    let details    = app.now_playing.and_then(|i| app.songs.get(i)).map(song_details).unwrap_or_default();
 
     let lines = vec![
         Line::from(""),
         Line::from(vec![Span::styled("  Artist:  ", Style::default().fg(Color::Cyan)), Span::raw(now_artist)]),
         Line::from(vec![Span::styled("  Album:   ", Style::default().fg(Color::Cyan)), Span::raw(now_album)]),
+        Line::from(Span::styled(format!("  {}", details), Style::default().fg(Color::DarkGray))),
         Line::from(""),
         Line::from(vec![Span::styled("  In Queue:    ", Style::default().fg(Color::DarkGray)), Span::styled(format!("{}", queued), Style::default().fg(Color::White))]),
         Line::from(vec![Span::styled("  Remaining:   ", Style::default().fg(Color::DarkGray)), Span::styled(format!("{}", remaining), Style::default().fg(Color::White))]),
@@ -0,0 +1,62 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    prelude::{Frame, Line},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Paragraph},
+};
+use std::sync::atomic::Ordering;
+
+use crate::app::App;
+use crate::lyrics::LyricsSource;
+
+pub fn render_lyrics_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let playing = app.now_playing.and_then(|i| app.songs.get(i));
+    let lyrics  = app.lyrics.as_ref()
+        .filter(|_| playing.is_some_and(|s| app.lyrics_song_id.as_deref() == Some(s.id.as_str())));
+
+    let title = match lyrics {
+        Some(l) if l.source == LyricsSource::LocalFile => " 🎤 Lyrics (.lrc) ".to_string(),
+        Some(l) if l.synced => " 🎤 Lyrics (synced) ".to_string(),
+        Some(_)             => " 🎤 Lyrics – PgUp/PgDn ".to_string(),
+        None                => " 🎤 Lyrics ".to_string(),
+    };
+    let block  = Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(Color::Magenta));
+    let height = (area.height as usize).saturating_sub(2);
+
+    let Some(lyrics) = lyrics else {
+        let text = if playing.is_none() { "Nothing playing" }
+            else if app.lyrics_loading() { "Loading lyrics…" }
+            else { "No lyrics found" };
+        frame.render_widget(
+            Paragraph::new(text).style(Style::default().fg(Color::DarkGray)).alignment(Alignment::Center).block(block),
+            area,
+        );
+        return;
+    };
+
+    // Synced lyrics keep the current line in the middle of the panel
+    let current = lyrics.current_line(app.player_status.current_time.load(Ordering::Relaxed) as u64);
+    let scroll  = match current {
+        Some(i) => i.saturating_sub(height / 2),
+        None if lyrics.synced => 0,
+        None => app.lyrics_scroll,
+    };
+
+    let lines: Vec<Line> = lyrics.lines.iter()
+        .enumerate()
+        .skip(scroll)
+        .take(height)
+        .map(|(i, line)| {
+            let style = if Some(i) == current {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else if lyrics.synced {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            Line::styled(line.text.clone(), style)
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center).block(block), area);
+}
@@ -2,6 +2,19 @@ pub mod panels;
 pub mod jukebox_panels;
 pub mod help;
 pub mod search_input;
+pub mod album_list_panels;
+pub mod genre_panels;
+pub mod playlist_picker;
+pub mod folder_picker;
+pub mod lyrics_panel;
+pub mod chat_panel;
+pub mod artist_info_panels;
+pub mod station_panels;
+pub mod podcast_panels;
+pub mod bookmark_panels;
+pub mod share_panels;
+pub mod folder_panels;
+pub mod now_playing_panels;
 
 use ratatui::{
     layout::{Constraint, Layout},
@@ -15,17 +28,34 @@ use crate::app::{App, ViewMode};
 use panels::*;
 use jukebox_panels::*;
 use help::render_help;
-use search_input::render_search_input;
+use search_input::{render_input_prompt, render_search_input};
+use album_list_panels::render_album_list_panel;
+use genre_panels::render_genres_panel;
+use playlist_picker::render_playlist_picker;
+use folder_picker::render_folder_picker;
+use lyrics_panel::render_lyrics_panel;
+use chat_panel::render_chat_panel;
+use artist_info_panels::{render_artist_info_list, render_artist_info_panel};
+use station_panels::{render_station_details_panel, render_stations_panel};
+use podcast_panels::{render_podcast_channels_panel, render_podcast_episodes_panel};
+use bookmark_panels::{render_bookmark_details_panel, render_bookmarks_panel};
+use folder_panels::{render_folder_details_panel, render_folders_panel};
+use now_playing_panels::{render_now_playing_details_panel, render_now_playing_panel};
+use share_panels::{render_share_details_panel, render_share_popup, render_shares_panel};
 
 pub fn ui(frame: &mut Frame, app: &App) {
     if app.is_help_mode {
-        render_help(frame);
+        render_help(frame, app);
     } else if app.is_search_mode {
         render_search_input(frame, app);
     } else if app.mode == ViewMode::Visualizer {
         app.visualizer.render(frame, frame.size());
     } else {
         render_main(frame, app);
+        render_playlist_picker(frame, app);
+        render_folder_picker(frame, app);
+        render_input_prompt(frame, app);
+        render_share_popup(frame, app);
     }
 }
 
@@ -49,12 +79,48 @@ fn render_main(frame: &mut Frame, app: &App) {
         render_jukebox_left_panel(frame, app, panels[0]);
         render_jukebox_center_panel(frame, app, panels[1]);
         render_songs_panel(frame, app, panels[2]);
+    } else if let (ViewMode::ArtistInfo, Some(view)) = (app.mode, app.artist_info.as_ref()) {
+        render_artists_panel(frame, app, panels[0]);
+        render_artist_info_panel(frame, app, view, panels[1]);
+        render_artist_info_list(frame, app, view, panels[2]);
     } else {
         match app.mode {
             ViewMode::Playlists | ViewMode::PlaylistSongs => {
                 render_playlists_panel(frame, app, panels[0]);
                 render_playlist_context_panel(frame, app, panels[1]);
             }
+            ViewMode::Podcasts | ViewMode::PodcastEpisodes => {
+                render_podcast_channels_panel(frame, app, panels[0]);
+                render_podcast_episodes_panel(frame, app, panels[1]);
+            }
+            ViewMode::Bookmarks => {
+                render_bookmarks_panel(frame, app, panels[0]);
+                render_bookmark_details_panel(frame, app, panels[1]);
+            }
+            ViewMode::NowPlaying => {
+                render_now_playing_panel(frame, app, panels[0]);
+                render_now_playing_details_panel(frame, app, panels[1]);
+            }
+            ViewMode::Folders => {
+                render_folders_panel(frame, app, panels[0]);
+                render_folder_details_panel(frame, app, panels[1]);
+            }
+            ViewMode::Shares => {
+                render_shares_panel(frame, app, panels[0]);
+                render_share_details_panel(frame, app, panels[1]);
+            }
+            ViewMode::Stations => {
+                render_stations_panel(frame, app, panels[0]);
+                render_station_details_panel(frame, app, panels[1]);
+            }
+            _ if app.mode == ViewMode::Genres || app.current_genre.is_some() => {
+                render_genres_panel(frame, app, panels[0]);
+                render_albums_panel(frame, app, panels[1]);
+            }
+            _ if app.mode == ViewMode::AlbumList || app.album_list.is_some() => {
+                render_album_list_panel(frame, app, panels[0]);
+                render_albums_panel(frame, app, panels[1]);
+            }
             _ => {
                 render_artists_panel(frame, app, panels[0]);
                 render_albums_panel(frame, app, panels[1]);
@@ -62,6 +128,14 @@ fn render_main(frame: &mut Frame, app: &App) {
         }
         render_songs_panel(frame, app, panels[2]);
     }
+    // Lyrics take over the center column in every view
+    if app.show_lyrics {
+        render_lyrics_panel(frame, app, panels[1]);
+    }
+    // …and the chat the right one
+    if app.show_chat {
+        render_chat_panel(frame, app, panels[2]);
+    }
 
     let divider       = "─".repeat(frame.size().width as usize);
     let divider_style = Style::default().fg(Color::DarkGray);
@@ -100,7 +174,10 @@ fn render_main(frame: &mut Frame, app: &App) {
         Span::styled("S", Style::new().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
         Span::styled(":Shuffle", Style::new().fg(Color::DarkGray)),
     ];
-    if app.is_jukebox_mode {
+    if app.radio_seed.is_some() {
+        status_spans.push(Span::raw(" | "));
+        status_spans.push(Span::styled("📻 RADIO", Style::new().fg(Color::Green).add_modifier(Modifier::BOLD)));
+    } else if app.is_jukebox_mode {
         status_spans.push(Span::raw(" | "));
         status_spans.push(Span::styled("🎉 JUKEBOX", Style::new().fg(Color::Green).add_modifier(Modifier::BOLD)));
     }
@@ -111,19 +188,33 @@ fn render_main(frame: &mut Frame, app: &App) {
     frame.render_widget(Paragraph::new(Line::from(status_spans)), main_layout[2]);
 
     // Now playing info
-    let song_info = app.now_playing
+    let station_info = app.current_station.as_ref().map(|station| {
+        let title = app.player_status.stream_title.lock().unwrap().clone();
+        if title.is_empty() { format!("📡 {}", station.name) } else { format!("📡 {} – {}", station.name, title) }
+    });
+    let song_info = station_info.or_else(|| app.now_playing
         .and_then(|i| app.songs.get(i))
         .map(|song| {
-            let prefix = if app.is_jukebox_mode { "🎉" } else if app.is_shuffle { "🔀" } else { "▶" };
+            let prefix = if app.radio_seed.is_some() { "📻" } else if app.is_jukebox_mode { "🎉" } else if app.is_shuffle { "🔀" } else { "▶" };
             format!("{} {} - {}", prefix, song.artist.as_deref().unwrap_or("Unknown"), song.title)
-        })
+        }))
         .unwrap_or_else(|| "⏹ Stopped".into());
 
+    let info_line = Layout::horizontal([Constraint::Min(10), Constraint::Length(
+        (app.status_message.chars().count() as u16 + 1).min(frame.size().width / 2),
+    )]).split(main_layout[4]);
     frame.render_widget(
         Paragraph::new(song_info).style(Style::default().fg(
             if app.is_jukebox_mode { Color::Green } else if app.is_shuffle { Color::Magenta } else { Color::Yellow }
         )),
-        main_layout[4],
+        info_line[0],
+    );
+    let status_color = if app.status_message.starts_with('❌') { Color::Red } else { Color::DarkGray };
+    frame.render_widget(
+        Paragraph::new(app.status_message.as_str())
+            .style(Style::default().fg(status_color))
+            .alignment(Alignment::Right),
+        info_line[1],
     );
 
     // Progress bar
@@ -137,13 +228,19 @@ fn render_main(frame: &mut Frame, app: &App) {
 
     let bar_width = (frame.size().width as usize).saturating_sub(20).max(10);
     let filled    = ((current as f32 / total.max(1) as f32 * bar_width as f32).round() as usize).min(bar_width);
-    let progress_bar = format!(
-        "{:02}:{:02} ┃{}{}┃ {:02}:{:02}",
-        current / 60, current % 60,
-        "━".repeat(filled),
-        "─".repeat(bar_width.saturating_sub(filled)),
-        total / 60, total % 60,
-    );
+    // Streams have no duration – count up and mark the bar as live
+    let progress_bar = if app.current_station.is_some() {
+        let elapsed = (app.player_status.current_time.load(Ordering::Relaxed) as u64) / 1000;
+        format!("{:02}:{:02} ┃{:─^width$}┃ --:--", elapsed / 60, elapsed % 60, " ● LIVE ", width = bar_width)
+    } else {
+        format!(
+            "{:02}:{:02} ┃{}{}┃ {:02}:{:02}",
+            current / 60, current % 60,
+            "━".repeat(filled),
+            "─".repeat(bar_width.saturating_sub(filled)),
+            total / 60, total % 60,
+        )
+    };
     frame.render_widget(
         Paragraph::new(progress_bar)
             .style(Style::default().fg(
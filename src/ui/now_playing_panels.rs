@@ -0,0 +1,78 @@
+use ratatui::{
+    layout::Rect,
+    prelude::{Alignment, Frame, Line, Span},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{App, ViewMode};
+
+/// Left column: who is listening to what; our own entries are dimmed.
+pub fn render_now_playing_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let title  = format!(" 👥 Now playing ({}) ", app.now_playing_entries.len());
+    let border = if matches!(app.mode, ViewMode::NowPlaying) { Color::Cyan } else { Color::LightCyan };
+    let me     = &app.client.config().server.username;
+
+    let items: Vec<ListItem> = app.now_playing_entries
+        .iter()
+        .enumerate()
+        .skip(app.now_playing_state.scroll)
+        .take((area.height as usize).saturating_sub(2))
+        .map(|(i, entry)| {
+            let style = if app.now_playing_state.selected == i {
+                Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)
+            } else if entry.username == *me {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{}: ", entry.username), Style::default().fg(Color::Yellow)),
+                Span::styled(entry.song.title.clone(), style),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border))),
+        area,
+    );
+}
+
+/// Center column: the selected entry's track and player.
+pub fn render_now_playing_details_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let label = Style::default().fg(Color::Cyan);
+    let mut lines = vec![Line::from("")];
+    if let Some(entry) = app.now_playing_entries.get(app.now_playing_state.selected) {
+        let song = &entry.song;
+        lines.push(Line::from(Span::styled(format!("  {}", song.title), Style::default().fg(Color::White).add_modifier(Modifier::BOLD))));
+        lines.push(Line::from(""));
+        if let Some(artist) = &song.artist {
+            lines.push(Line::from(vec![Span::styled("  Artist:   ", label), Span::raw(artist.clone())]));
+        }
+        if let Some(album) = &song.album {
+            lines.push(Line::from(vec![Span::styled("  Album:    ", label), Span::raw(album.clone())]));
+        }
+        lines.push(Line::from(vec![Span::styled("  User:     ", label), Span::raw(entry.username.clone())]));
+        if let Some(player) = &entry.player_name {
+            lines.push(Line::from(vec![Span::styled("  Player:   ", label), Span::raw(player.clone())]));
+        }
+        let since = match entry.minutes_ago {
+            0 => "just now".to_string(),
+            m => format!("{} min ago", m),
+        };
+        lines.push(Line::from(vec![Span::styled("  Started:  ", label), Span::raw(since)]));
+    } else {
+        lines.push(Line::from(Span::styled("  Nobody is listening right now", Style::default().fg(Color::Gray))));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("  Enter – Queue track   Shift+W – Back", Style::default().fg(Color::DarkGray))));
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().title(" Listening ").borders(Borders::ALL).border_style(Style::default().fg(Color::Magenta)))
+            .alignment(Alignment::Left),
+        area,
+    );
+}
@@ -6,16 +6,17 @@ use ratatui::{
 };
 
 use crate::app::{App, ViewMode};
-use crate::cover::{default_cover_art, get_ascii_cover, COVER_CACHE};
+use crate::api::models::{AlbumListType, Song};
+use crate::cover::{cached_cover_or_fetch, default_cover_art, get_ascii_cover, COVER_CACHE};
 
 pub fn render_artists_panel(frame: &mut Frame, app: &App, area: Rect) {
-    let title = if app.search_results.is_empty() {
-        format!(" Artists ({}) ", app.artists.len())
-    } else {
-        " Search Mode ".to_string()
+    let title = match &app.search {
+        Some(search) => format!(" {} ({}) ", search.label("Artists"), app.artists.len()),
+        None         => format!(" Artists ({}) ", app.artists.len()),
     };
-    let border_color = if !app.search_results.is_empty() { Color::Yellow }
-        else if matches!(app.mode, ViewMode::Artists) { Color::Cyan }
+    let border_color = if matches!(app.mode, ViewMode::Artists) {
+            if app.search.is_some() { Color::Yellow } else { Color::Cyan }
+        }
         else if app.current_artist.is_some() { Color::LightCyan }
         else { Color::DarkGray };
 
@@ -27,7 +28,8 @@ pub fn render_artists_panel(frame: &mut Frame, app: &App, area: Rect) {
         .map(|(i, artist)| {
             let is_selected = app.artist_state.selected == i + app.artist_state.scroll;
             let style = if is_selected { Style::default().fg(Color::Blue) } else { Style::default().fg(Color::Gray) };
-            ListItem::new(artist.name.clone()).style(style)
+            let heart = if artist.starred.is_some() { "❤️ " } else { "" };
+            ListItem::new(format!("{}{}", heart, artist.name)).style(style)
         })
         .collect();
 
@@ -68,17 +70,18 @@ pub fn render_playlists_panel(frame: &mut Frame, app: &App, area: Rect) {
 pub fn render_albums_panel(frame: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::vertical([Constraint::Length(12), Constraint::Min(3)]).split(area);
 
-    let border_color = if !app.search_results.is_empty() { Color::Yellow }
-        else if matches!(app.mode, ViewMode::Albums) { Color::Cyan }
+    let border_color = if matches!(app.mode, ViewMode::Albums) {
+            if app.search.is_some() { Color::Yellow } else { Color::Cyan }
+        }
         else if app.current_album.is_some() { Color::LightCyan }
         else { Color::DarkGray };
 
     // Trigger async cover fetch
-    let config         = app.config.clone();
+    let client         = app.client.clone();
     let selected_album = app.albums.get(app.album_state.selected).cloned();
     tokio::spawn(async move {
         if let Some(album) = selected_album {
-            let _ = get_ascii_cover(Some(&album), &config).await;
+            let _ = get_ascii_cover(Some(&album), &client).await;
         }
     });
 
@@ -106,9 +109,13 @@ pub fn render_albums_panel(frame: &mut Frame, app: &App, area: Rect) {
         chunks[0],
     );
 
-    let title = if app.search_results.is_empty() {
+    let title = if let Some(list) = &app.album_list {
+        format!(" {} ({}) ", list.label(), app.albums.len())
+    } else if let (Some(search), None) = (&app.search, &app.current_artist) {
+        format!(" {} ({}) ", search.label("Albums"), app.albums.len())
+    } else {
         match app.albums.len() { 0 => " Albums ".to_string(), n => format!(" Albums ({}) ", n) }
-    } else { " Results ".to_string() };
+    };
 
     let items: Vec<ListItem> = app.albums
         .iter()
@@ -122,7 +129,15 @@ pub fn render_albums_panel(frame: &mut Frame, app: &App, area: Rect) {
             let style = if is_active { Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD) }
                 else if is_sel { Style::default().fg(Color::Blue) }
                 else { Style::default().fg(Color::Gray) };
-            ListItem::new(format!("{} ({})", album.name, album.year.unwrap_or(0))).style(style)
+            let text = match &app.album_list {
+                // Library browser: the album name alone doesn't say much
+                Some(AlbumListType::Newest) => format!("{} – {} [{}]", album.artist, album.name,
+                    album.created.as_deref().and_then(|c| c.get(..10)).unwrap_or("?")),
+                Some(_) => format!("{} – {} ({})", album.artist, album.name, album.year.unwrap_or(0)),
+                None    => format!("{} ({})", album.name, album.year.unwrap_or(0)),
+            };
+            let heart = if album.starred.is_some() { "❤️ " } else { "" };
+            ListItem::new(format!("{}{}{}", heart, text, rating_stars(album.user_rating))).style(style)
         })
         .collect();
 
@@ -133,7 +148,9 @@ pub fn render_albums_panel(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 pub fn render_songs_panel(frame: &mut Frame, app: &App, area: Rect) {
-    let title = if app.is_jukebox_mode {
+    let title = if app.radio_seed.is_some() {
+        format!(" 📻 Radio Queue ({}) ", app.songs.len())
+    } else if app.is_jukebox_mode {
         format!(" 🎉 Jukebox Queue ({}) ", app.songs.len())
     } else if app.is_shuffle {
         match app.mode {
@@ -144,8 +161,8 @@ pub fn render_songs_panel(frame: &mut Frame, app: &App, area: Rect) {
                 app.current_album.as_ref().map(|a| format!(" 🔀 {} ({}) ", a.name, app.songs.len()))
                     .unwrap_or_else(|| " 🔀 Shuffled ".to_string()),
         }
-    } else if !app.search_results.is_empty() {
-        format!(" Search: '{}' ({}) ", app.search_query, app.songs.len())
+    } else if let (Some(search), None) = (&app.search, &app.current_album) {
+        format!(" {} ({}) ", search.label("Songs"), app.songs.len())
     } else {
         match app.mode {
             ViewMode::PlaylistSongs =>
@@ -160,7 +177,7 @@ pub fn render_songs_panel(frame: &mut Frame, app: &App, area: Rect) {
     let is_active_songs = matches!(app.mode, ViewMode::Songs | ViewMode::PlaylistSongs | ViewMode::Jukebox | ViewMode::Visualizer);
     let border_style = if app.is_jukebox_mode { Style::default().fg(Color::Green) }
         else if app.is_shuffle { Style::default().fg(Color::Magenta) }
-        else if !app.search_results.is_empty() { Style::default().fg(Color::Yellow) }
+        else if is_active_songs && app.search.is_some() { Style::default().fg(Color::Yellow) }
         else if is_active_songs { Style::default().fg(Color::Cyan) }
         else if app.now_playing.is_some() { Style::default().fg(Color::LightCyan) }
         else { Style::default().fg(Color::DarkGray) };
@@ -189,7 +206,7 @@ pub fn render_songs_panel(frame: &mut Frame, app: &App, area: Rect) {
                 (None, Some(al))    => format!("{}{} - {:02}:{:02} - {}", heart, al, mins, secs, song.title),
                 _                   => format!("{}{:02}:{:02} - {}", heart, mins, secs, song.title),
             };
-            ListItem::new(text).style(style)
+            ListItem::new(format!("{}{}", text, rating_stars(song.user_rating))).style(style)
         })
         .collect();
 
@@ -202,17 +219,13 @@ pub fn render_songs_panel(frame: &mut Frame, app: &App, area: Rect) {
 pub fn render_playlist_context_panel(frame: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::vertical([Constraint::Length(12), Constraint::Min(3)]).split(area);
 
-    let cover = if let Some(i) = app.now_playing {
-        if let Some(song) = app.songs.get(i) {
-            if let Some(album_name) = song.album.as_deref() {
-                if let Some(album) = app.albums.iter().find(|a| a.name == album_name) {
-                    if let Some(cover_id) = album.cover_art.as_deref() {
-                        COVER_CACHE.lock().unwrap().get(cover_id).cloned().unwrap_or_else(default_cover_art)
-                    } else { default_cover_art() }
-                } else { default_cover_art() }
-            } else { default_cover_art() }
-        } else { default_cover_art() }
-    } else { default_cover_art() };
+    // Songs carry their own coverArt id – no need to find the album first
+    let cover_id = app.now_playing
+        .and_then(|i| app.songs.get(i))
+        .and_then(|s| s.cover_art.clone())
+        .or_else(|| app.current_playlist.as_ref().and_then(|p| p.cover_art.clone()));
+    let cover = cover_id.and_then(|id| cached_cover_or_fetch(&id, &app.client))
+        .unwrap_or_else(default_cover_art);
 
     let title = match app.mode {
         ViewMode::Playlists     => " Playlist ",
@@ -263,6 +276,16 @@ pub fn render_playlist_context_panel(frame: &mut Frame, app: &App, area: Rect) {
                 Span::styled("Now: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                 Span::raw(format!("{} – {}", song.artist.as_deref().unwrap_or("Unknown"), song.title)),
             ]));
+            if let Some(album) = song.album.as_deref() {
+                info.push(Line::from(vec![
+                    Span::styled("Album: ", Style::default().fg(Color::DarkGray)),
+                    Span::raw(album.to_string()),
+                ]));
+            }
+            let details = song_details(song);
+            if !details.is_empty() {
+                info.push(Line::from(Span::styled(details, Style::default().fg(Color::DarkGray))));
+            }
         }
     }
 
@@ -273,3 +296,35 @@ pub fn render_playlist_context_panel(frame: &mut Frame, app: &App, area: Rect) {
         chunks[1],
     );
 }
+
+/// ` ★★★☆☆` for a 1–5 rating, empty when unrated.
+pub fn rating_stars(rating: Option<u8>) -> String {
+    match rating {
+        Some(r @ 1..=5) => format!(" {}{}", "★".repeat(r as usize), "☆".repeat(5 - r as usize)),
+        _               => String::new(),
+    }
+}
+
+/// Compact metadata line, e.g. `1976 · Pop · FLAC 1411 kbps · CD 1 #3 · 12 plays`.
+pub fn song_details(song: &Song) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(year) = song.year { parts.push(year.to_string()); }
+    if let Some(genre) = song.genre.as_deref() { parts.push(genre.to_string()); }
+    match (song.suffix.as_deref(), song.bit_rate) {
+        (Some(sfx), Some(br)) => parts.push(format!("{} {} kbps", sfx.to_uppercase(), br)),
+        (Some(sfx), None)     => parts.push(sfx.to_uppercase()),
+        (None, Some(br))      => parts.push(format!("{} kbps", br)),
+        (None, None)          => {}
+    }
+    match (song.disc_number, song.track) {
+        (Some(d), Some(t)) => parts.push(format!("CD {} #{}", d, t)),
+        (None, Some(t))    => parts.push(format!("#{}", t)),
+        _                  => {}
+    }
+    if let Some(size) = song.size { parts.push(format!("{:.1} MB", size as f64 / 1_048_576.0)); }
+    if let Some(plays) = song.play_count { parts.push(format!("{} plays", plays)); }
+    if let Some(gain) = song.replay_gain.as_ref().and_then(|g| g.track_gain) {
+        parts.push(format!("RG {:+.1} dB", gain));
+    }
+    parts.join(" · ")
+}
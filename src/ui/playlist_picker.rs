@@ -0,0 +1,44 @@
+use ratatui::{
+    layout::Rect,
+    prelude::Frame,
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, List, ListItem},
+};
+
+use crate::app::App;
+
+/// Shift+P overlay: the playlists plus a "New playlist…" row.
+pub fn render_playlist_picker(frame: &mut Frame, app: &App) {
+    let Some(picker) = &app.playlist_picker else { return; };
+    let sz     = frame.size();
+    let height = (app.playlists.len() as u16 + 3).min(sz.height.saturating_sub(4)).max(3);
+    let width  = (sz.width / 2).max(30).min(sz.width);
+    let area   = Rect {
+        x: sz.width.saturating_sub(width) / 2,
+        y: sz.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    let visible = (height as usize).saturating_sub(2);
+    let scroll  = (picker.selected + 1).saturating_sub(visible);
+    let items: Vec<ListItem> = app.playlists
+        .iter()
+        .map(|pl| format!("♪ {} ({})", pl.name, pl.song_count))
+        .chain(std::iter::once("➕ New playlist…".to_string()))
+        .enumerate()
+        .skip(scroll)
+        .take(visible)
+        .map(|(i, text)| {
+            let style = if i == picker.selected { Style::default().fg(Color::Blue) } else { Style::default().fg(Color::Gray) };
+            ListItem::new(text).style(style)
+        })
+        .collect();
+
+    let title = format!(" Add {} to playlist – Tab: song/album/queue ", picker.source.label());
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow))),
+        area,
+    );
+}
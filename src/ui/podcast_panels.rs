@@ -0,0 +1,92 @@
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    prelude::{Frame, Line, Span},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{episode_status_label, App, ViewMode};
+
+/// Left column: "newest episodes" followed by the subscribed channels.
+pub fn render_podcast_channels_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let title  = format!(" 🎙 Podcasts ({}) ", app.podcasts.len());
+    let border = if matches!(app.mode, ViewMode::Podcasts) { Color::Cyan } else { Color::LightCyan };
+
+    let newest = format!("🆕 Newest episodes ({})", app.newest_episodes.len());
+    let names  = std::iter::once(newest).chain(app.podcasts.iter().map(|c| {
+        let error = if c.status == "error" { " ⚠" } else { "" };
+        format!("{} ({}){}", c.display_title(), c.episode.len(), error)
+    }));
+
+    let items: Vec<ListItem> = names
+        .enumerate()
+        .skip(app.podcast_state.scroll)
+        .take((area.height as usize).saturating_sub(2))
+        .map(|(i, name)| {
+            let style = if app.podcast_state.selected == i {
+                Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            ListItem::new(name).style(style)
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border))),
+        area,
+    );
+}
+
+/// Center column: episodes with their download status, and the selected one's description.
+pub fn render_podcast_episodes_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks   = Layout::vertical([Constraint::Min(5), Constraint::Length(8)]).split(area);
+    let episodes = app.podcast_episodes();
+    let border   = if matches!(app.mode, ViewMode::PodcastEpisodes) { Color::Cyan } else { Color::DarkGray };
+    let title    = app.selected_channel()
+        .map(|c| format!(" {} ", c.display_title()))
+        .unwrap_or_else(|| " Newest episodes ".to_string());
+
+    let items: Vec<ListItem> = episodes
+        .iter()
+        .enumerate()
+        .skip(app.episode_state.scroll)
+        .take((chunks[0].height as usize).saturating_sub(2))
+        .map(|(i, episode)| {
+            let is_sel  = app.episode_state.selected == i && matches!(app.mode, ViewMode::PodcastEpisodes);
+            let playing = episode.stream_id.is_some()
+                && app.now_playing.and_then(|i| app.songs.get(i)).map(|s| s.id.as_str()) == episode.stream_id.as_deref();
+            let style = if playing { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) }
+                else if is_sel { Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD) }
+                else if episode.status == "completed" { Style::default().fg(Color::Gray) }
+                else { Style::default().fg(Color::DarkGray) };
+            let date = episode.publish_date.as_deref().and_then(|d| d.get(..10)).unwrap_or("");
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", episode_status_label(&episode.status))),
+                Span::styled(episode.title.clone(), style),
+                Span::styled(format!("  {}", date), Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border))),
+        chunks[0],
+    );
+
+    let selected    = episodes.get(app.episode_state.selected);
+    let description = selected.and_then(|e| e.description.as_deref()).unwrap_or("");
+    let status      = selected.map(|e| match e.status.as_str() {
+        "completed"   => "Downloaded – Enter plays (resumes from its bookmark)",
+        "downloading" => "Downloading on the server…",
+        _             => "Not downloaded – Enter downloads",
+    }).unwrap_or("");
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(Span::styled(status, Style::default().fg(Color::Cyan))),
+            Line::from(Span::styled(description, Style::default().fg(Color::Gray))),
+        ])
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::DarkGray))),
+        chunks[1],
+    );
+}
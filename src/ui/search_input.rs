@@ -2,7 +2,7 @@ use ratatui::{
     layout::Rect,
     prelude::Frame,
     style::{Color, Style},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
 };
 use crate::app::App;
 
@@ -20,3 +20,21 @@ pub fn render_search_input(frame: &mut Frame, app: &App) {
         area,
     );
 }
+
+/// One-line prompt for `app.input` (year range, genre, …).
+pub fn render_input_prompt(frame: &mut Frame, app: &App) {
+    let Some(prompt) = &app.input else { return; };
+    let area = Rect {
+        x:      frame.size().width / 4,
+        y:      frame.size().height / 2,
+        width:  frame.size().width / 2,
+        height: 3,
+    };
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(prompt.text.as_str())
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::default().borders(Borders::ALL).title(prompt.purpose.title())),
+        area,
+    );
+}
@@ -0,0 +1,95 @@
+use ratatui::{
+    layout::Rect,
+    prelude::{Alignment, Frame, Line, Span},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{App, ViewMode};
+
+/// Left column: the user's share links.
+pub fn render_shares_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let title  = format!(" 🔗 Shares ({}) ", app.shares.len());
+    let border = if matches!(app.mode, ViewMode::Shares) { Color::Cyan } else { Color::LightCyan };
+
+    let items: Vec<ListItem> = app.shares
+        .iter()
+        .enumerate()
+        .skip(app.share_state.scroll)
+        .take((area.height as usize).saturating_sub(2))
+        .map(|(i, share)| {
+            let style = if app.share_state.selected == i {
+                Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            let name = share.description.clone().filter(|d| !d.is_empty()).unwrap_or_else(|| share.url.clone());
+            ListItem::new(Line::from(vec![
+                Span::styled(name, style),
+                Span::styled(format!("  👁 {}", share.visit_count), Style::default().fg(Color::DarkGray)),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border))),
+        area,
+    );
+}
+
+/// Center column: link, dates and shared songs of the selected share.
+pub fn render_share_details_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let label = Style::default().fg(Color::Cyan);
+    let date  = |d: &Option<String>| d.as_deref().and_then(|d| d.get(..10)).unwrap_or("–").to_string();
+    let mut lines = vec![Line::from("")];
+    if let Some(share) = app.shares.get(app.share_state.selected) {
+        lines.push(Line::from(vec![Span::styled("  Link:     ", label), Span::styled(share.url.clone(), Style::default().fg(Color::Yellow))]));
+        lines.push(Line::from(vec![Span::styled("  Created:  ", label), Span::raw(date(&share.created))]));
+        lines.push(Line::from(vec![Span::styled("  Expires:  ", label), Span::raw(date(&share.expires))]));
+        lines.push(Line::from(vec![Span::styled("  Visits:   ", label), Span::raw(share.visit_count.to_string())]));
+        lines.push(Line::from(""));
+        for song in &share.entry {
+            lines.push(Line::from(Span::styled(format!("  ♪ {}", song.title), Style::default().fg(Color::Gray))));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("  Enter – Copy link   Delete – Remove   Shift+V – Back", Style::default().fg(Color::DarkGray))));
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().title(" Share ").borders(Borders::ALL).border_style(Style::default().fg(Color::Magenta)))
+            .alignment(Alignment::Left),
+        area,
+    );
+}
+
+/// Overlay with a new (or selected) share link; any key closes it.
+pub fn render_share_popup(frame: &mut Frame, app: &App) {
+    let Some(share) = &app.share_popup else { return; };
+    let sz     = frame.size();
+    let width  = (share.url.chars().count() as u16 + 6).max(40).min(sz.width);
+    let height = 7.min(sz.height);
+    let area   = Rect {
+        x: sz.width.saturating_sub(width) / 2,
+        y: sz.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    let lines = vec![
+        Line::from(Span::styled(share.description.clone().unwrap_or_default(), Style::default().fg(Color::Gray))),
+        Line::from(""),
+        Line::from(Span::styled(share.url.clone(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(Span::styled("Copied to the clipboard (OSC 52) – any key closes", Style::default().fg(Color::DarkGray))),
+    ];
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .alignment(Alignment::Center)
+            .block(Block::default().title(" 🔗 Share link ").borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow))),
+        area,
+    );
+}
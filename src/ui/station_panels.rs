@@ -0,0 +1,68 @@
+use ratatui::{
+    layout::Rect,
+    prelude::{Alignment, Frame, Line, Span},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+
+use crate::app::{App, ViewMode};
+
+pub fn render_stations_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let title  = format!(" 📡 Stations ({}) ", app.stations.len());
+    let border = if matches!(app.mode, ViewMode::Stations) { Color::Cyan } else { Color::LightCyan };
+
+    let items: Vec<ListItem> = app.stations
+        .iter()
+        .skip(app.station_state.scroll)
+        .take((area.height as usize).saturating_sub(2))
+        .enumerate()
+        .map(|(i, station)| {
+            let abs       = i + app.station_state.scroll;
+            let is_sel    = app.station_state.selected == abs;
+            let is_active = app.current_station.as_ref().map(|s| s.id.as_str()) == Some(station.id.as_str());
+            let style = if is_active { Style::default().fg(Color::Blue).add_modifier(Modifier::BOLD) }
+                else if is_sel { Style::default().fg(Color::Blue) }
+                else { Style::default().fg(Color::Gray) };
+            let marker = if is_active { "▶ " } else { "" };
+            ListItem::new(format!("{}{}", marker, station.name)).style(style)
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(Block::default().title(title).borders(Borders::ALL).border_style(Style::default().fg(border))),
+        area,
+    );
+}
+
+/// Selected station's URLs and, while one plays, its ICY stream title.
+pub fn render_station_details_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let label = Style::default().fg(Color::Cyan);
+    let mut lines = vec![Line::from("")];
+    if let Some(station) = app.stations.get(app.station_state.selected) {
+        lines.push(Line::from(Span::styled(format!("  {}", station.name), Style::default().fg(Color::White).add_modifier(Modifier::BOLD))));
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled("  Stream:   ", label), Span::raw(station.stream_url.clone())]));
+        if let Some(home) = &station.home_page_url {
+            lines.push(Line::from(vec![Span::styled("  Homepage: ", label), Span::raw(home.clone())]));
+        }
+    }
+    if let Some(playing) = &app.current_station {
+        let title = app.player_status.stream_title.lock().unwrap().clone();
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![Span::styled("  On air:   ", label), Span::raw(playing.name.clone())]));
+        lines.push(Line::from(vec![
+            Span::styled("  Now:      ", label),
+            Span::styled(if title.is_empty() { "–".to_string() } else { title }, Style::default().fg(Color::Yellow)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("  Enter – Play   Shift+T – Back", Style::default().fg(Color::DarkGray))));
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().title(" Internet Radio ").borders(Borders::ALL).border_style(Style::default().fg(Color::Magenta)))
+            .alignment(Alignment::Left),
+        area,
+    );
+}
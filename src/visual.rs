@@ -1,7 +1,8 @@
 use anyhow::Result;
 use std::{
+    collections::BTreeMap,
     io::{BufRead, BufReader},
-    process::{Child, Command, Stdio},
+    process::{Child, ChildStdin, Command, Stdio},
     sync::{Arc, Mutex},
     sync::atomic::{AtomicBool, AtomicU64, Ordering},
     time::{Duration, Instant},
@@ -19,12 +20,13 @@ use ratatui::{
 /// Audio pipeline (Linux + macOS, no loopback device needed):
 ///
 ///   mpv    ──plays──▶  speakers
-///   ffmpeg ──reads same URL──▶  s16le PCM ──▶  named FIFO  (metered, real-time)
+///   ffmpeg ◀──same URL, fetched by us into its stdin──  s16le PCM ──▶  named FIFO  (metered, real-time)
 ///   cava   ──reads FIFO──▶  bar levels ──▶  this widget
 ///
 /// A watchdog thread monitors both cava and ffmpeg and restarts them when they
 /// die (e.g. after a track change or EOF). This keeps the visualizer alive
-/// across the entire playback session.
+/// across the entire playback session. The stream URL carries credentials, so
+/// ffmpeg never sees it – its argv would show up in `ps`.
 ///
 /// Fallback: demo animation when cava/ffmpeg are unavailable.
 #[derive(Debug)]
@@ -46,6 +48,8 @@ pub struct Visualizer {
     // Current stream info, written by main thread, read by watchdog
     current_url:      Arc<Mutex<String>>,
     current_seek_sec: Arc<AtomicU64>,
+    // Extra HTTP headers for the stream request
+    http_headers:     Arc<Mutex<Vec<(String, String)>>>,
 
     // FIFO path
     fifo_path: Option<std::path::PathBuf>,
@@ -59,7 +63,7 @@ pub struct Visualizer {
 
 impl Visualizer {
     pub fn new(bars: usize) -> Self {
-        let bars   = bars.max(1).min(32);
+        let bars   = bars.clamp(1, 32);
         let levels = vec![0.0; bars];
         let shared = Arc::new(Mutex::new(levels.clone()));
         Self {
@@ -74,6 +78,7 @@ impl Visualizer {
             ffmpeg_stop:      Arc::new(AtomicBool::new(false)),
             current_url:      Arc::new(Mutex::new(String::new())),
             current_seek_sec: Arc::new(AtomicU64::new(0)),
+            http_headers:     Arc::new(Mutex::new(Vec::new())),
             fifo_path:        None,
             watchdog_handle:  None,
             cava_running:     Arc::new(AtomicBool::new(false)),
@@ -85,7 +90,7 @@ impl Visualizer {
 
     #[allow(dead_code)]
     pub fn frame_budget(&self) -> Duration {
-        Duration::from_millis((1000 / self.fps.max(1).min(60)) as u64)
+        Duration::from_millis((1000 / self.fps.clamp(1, 60)) as u64)
     }
 
     pub fn fifo_path(&self) -> Option<&std::path::Path> {
@@ -127,7 +132,7 @@ impl Visualizer {
         // Write cava config
         let bars      = self.bars;
         let ascii_max = 100u32;
-        let fps       = self.fps.max(1).min(60);
+        let fps       = self.fps.clamp(1, 60);
         let cfg = format!(
             "[general]\nframerate={fps}\nbars={bars}\n\
              lower_cutoff_freq=50\nhigher_cutoff_freq=10000\n\n\
@@ -157,6 +162,7 @@ impl Visualizer {
         let last_frame   = self.last_cava_frame.clone();
         let cur_url      = self.current_url.clone();
         let cur_seek     = self.current_seek_sec.clone();
+        let cur_headers  = self.http_headers.clone();
         let cava_running = self.cava_running.clone();
         let fifo_path    = fifo.clone();
         let cfg_path_w   = cfg_path.clone();
@@ -164,7 +170,7 @@ impl Visualizer {
         let handle = std::thread::spawn(move || {
             Self::watchdog(
                 stop, ffmpeg_stop, shared, last_frame,
-                cur_url, cur_seek, cava_running,
+                cur_url, cur_seek, cur_headers, cava_running,
                 fifo_path, cfg_path_w,
                 bars, ascii_max,
             );
@@ -183,6 +189,13 @@ impl Visualizer {
         self.ffmpeg_stop.store(true, Ordering::Relaxed);
     }
 
+    /// Extra HTTP headers sent with the stream request.
+    pub fn set_http_headers(&mut self, headers: &BTreeMap<String, String>) {
+        if let Ok(mut h) = self.http_headers.lock() {
+            *h = headers.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        }
+    }
+
     /// Stop only ffmpeg (e.g. playback paused / stopped). cava keeps running.
     pub fn stop_ffmpeg_feeder(&mut self) {
         if let Ok(mut u) = self.current_url.lock() { u.clear(); }
@@ -204,6 +217,7 @@ impl Visualizer {
 
     /// Owns cava and ffmpeg processes. Restarts them whenever they die.
     /// Runs until `stop` is set.
+    #[allow(clippy::too_many_arguments)]
     fn watchdog(
         stop:         Arc<AtomicBool>,
         ffmpeg_stop:  Arc<AtomicBool>,
@@ -211,6 +225,7 @@ impl Visualizer {
         last_frame:   Arc<Mutex<Option<Instant>>>,
         cur_url:      Arc<Mutex<String>>,
         cur_seek:     Arc<AtomicU64>,
+        cur_headers:  Arc<Mutex<Vec<(String, String)>>>,
         cava_running: Arc<AtomicBool>,
         fifo_path:    std::path::PathBuf,
         cfg_path:     std::path::PathBuf,
@@ -253,7 +268,7 @@ impl Visualizer {
             cur_seek.store(0, Ordering::Relaxed);
 
             Self::maybe_start_ffmpeg(
-                &cur_url, &cur_seek, &fifo_path,
+                &cur_url, &cur_seek, &cur_headers, &fifo_path,
                 &stop, &ffmpeg_stop,
                 &mut ffmpeg_child,
                 &mut ffmpeg_stdout_reader,
@@ -261,7 +276,7 @@ impl Visualizer {
 
             // ── cava reader loop ──────────────────────────────────────────────
             let reader = BufReader::new(stdout);
-            for line in reader.lines().flatten() {
+            for line in reader.lines().map_while(Result::ok) {
                 if stop.load(Ordering::Relaxed) { break; }
 
                 // Update liveness timestamp
@@ -292,7 +307,7 @@ impl Visualizer {
 
                     // Start new ffmpeg if we have a URL
                     Self::maybe_start_ffmpeg(
-                        &cur_url, &cur_seek, &fifo_path,
+                        &cur_url, &cur_seek, &cur_headers, &fifo_path,
                         &stop, &ffmpeg_stop,
                         &mut ffmpeg_child,
                         &mut ffmpeg_stdout_reader,
@@ -322,9 +337,11 @@ impl Visualizer {
     }
 
     /// Spawn an ffmpeg feeder for the current URL, if one is set.
+    #[allow(clippy::too_many_arguments)]
     fn maybe_start_ffmpeg(
         cur_url:   &Arc<Mutex<String>>,
         cur_seek:  &Arc<AtomicU64>,
+        cur_headers: &Arc<Mutex<Vec<(String, String)>>>,
         fifo_path: &std::path::PathBuf,
         stop:      &Arc<AtomicBool>,
         ffmpeg_stop: &Arc<AtomicBool>,
@@ -355,23 +372,29 @@ impl Visualizer {
         if stop.load(Ordering::Relaxed) || ffmpeg_stop.load(Ordering::Relaxed) { return; }
 
         let seek_str = seek_sec.to_string();
+        let headers  = cur_headers.lock().map(|h| h.clone()).unwrap_or_default();
         let child = Command::new("ffmpeg")
             .args([
                 "-loglevel", "quiet",
                 "-ss",       &seek_str,
-                "-i",        &url,
+                "-i",        "pipe:0",
                 "-vn",
                 "-ar",       "44100",
                 "-ac",       "2",
                 "-f",        "s16le",
                 "pipe:1",
             ])
-            .stdin(Stdio::null())
+            .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::null())
             .spawn();
 
         if let Ok(mut c) = child {
+            if let Some(ffmpeg_in) = c.stdin.take() {
+                let stop_c   = stop.clone();
+                let ffstop_c = ffmpeg_stop.clone();
+                std::thread::spawn(move || Self::pipe_stream(&url, &headers, ffmpeg_in, &stop_c, &ffstop_c));
+            }
             if let Some(mut ffmpeg_out) = c.stdout.take() {
                 // Spawn a thread to meter PCM into the FIFO at real-time speed
                 let fifo_p      = fifo_path.clone();
@@ -407,6 +430,30 @@ impl Visualizer {
         }
     }
 
+    /// Downloads the stream into ffmpeg's stdin until it ends, ffmpeg goes
+    /// away or the feeder is stopped.
+    fn pipe_stream(
+        url:         &str,
+        headers:     &[(String, String)],
+        mut sink:    ChildStdin,
+        stop:        &AtomicBool,
+        ffmpeg_stop: &AtomicBool,
+    ) {
+        use std::io::Write;
+        let Ok(runtime) = tokio::runtime::Builder::new_current_thread().enable_all().build() else { return; };
+        runtime.block_on(async {
+            let mut request = reqwest::Client::new().get(url);
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+            let Ok(mut response) = request.send().await.and_then(|r| r.error_for_status()) else { return; };
+            while let Ok(Some(chunk)) = response.chunk().await {
+                if stop.load(Ordering::Relaxed) || ffmpeg_stop.load(Ordering::Relaxed) { break; }
+                if sink.write_all(&chunk).is_err() { break; }
+            }
+        });
+    }
+
     // ── tick & render ─────────────────────────────────────────────────────────
 
     pub fn tick(&mut self) {